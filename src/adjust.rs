@@ -0,0 +1,11 @@
+//! Relative updates of numeric type fields.
+
+mod numeric;
+mod operation;
+mod outcome;
+mod rounding;
+
+pub use numeric::Numeric;
+pub use operation::Operation;
+pub use outcome::Outcome;
+pub use rounding::Rounding;
@@ -0,0 +1,61 @@
+use crate::validate::MAX_AMOUNT;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A numeric type field
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Numeric {
+    Nominal,
+    Lifetime,
+    Restock,
+    Min,
+    Quantmin,
+    Quantmax,
+    Cost,
+}
+
+impl Numeric {
+    /// Return the range of values the field can hold.
+    ///
+    /// Nominal and min stop at [`MAX_AMOUNT`], the largest amount the game can hold.
+    /// Quantities do not reach below zero, as -1 means that a type holds no quantity at all.
+    #[must_use]
+    pub fn range(self) -> (i64, i64) {
+        match self {
+            Self::Nominal | Self::Min => (u32::MIN.into(), MAX_AMOUNT.into()),
+            Self::Lifetime | Self::Restock | Self::Cost => (u32::MIN.into(), u32::MAX.into()),
+            Self::Quantmin | Self::Quantmax => (0, i64::MAX),
+        }
+    }
+}
+
+impl Display for Numeric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nominal => write!(f, "nominal"),
+            Self::Lifetime => write!(f, "lifetime"),
+            Self::Restock => write!(f, "restock"),
+            Self::Min => write!(f, "min"),
+            Self::Quantmin => write!(f, "quantmin"),
+            Self::Quantmax => write!(f, "quantmax"),
+            Self::Cost => write!(f, "cost"),
+        }
+    }
+}
+
+impl FromStr for Numeric {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "nominal" => Ok(Self::Nominal),
+            "lifetime" => Ok(Self::Lifetime),
+            "restock" => Ok(Self::Restock),
+            "min" => Ok(Self::Min),
+            "quantmin" => Ok(Self::Quantmin),
+            "quantmax" => Ok(Self::Quantmax),
+            "cost" => Ok(Self::Cost),
+            _ => Err(anyhow::Error::msg(format!("not a numeric field: {name}"))),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::adjust::Rounding;
+
+/// A relative update of a numeric value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation {
+    Add(i64),
+    Subtract(i64),
+    Multiply(f64, Rounding),
+    /// Clamp the value to the inclusive range.
+    Clamp(i64, i64),
+}
+
+impl Operation {
+    /// Apply the operation to the given value and saturate the result to the given range.
+    ///
+    /// Returns the result and whether it was saturated.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the operation is invalid, see [`Operation::validate`].
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn apply(self, value: i64, (min, max): (i64, i64)) -> Result<(i64, bool), anyhow::Error> {
+        self.validate()?;
+        let result = match self {
+            Self::Add(amount) => i128::from(value) + i128::from(amount),
+            Self::Subtract(amount) => i128::from(value) - i128::from(amount),
+            Self::Multiply(factor, rounding) => {
+                let result = rounding.round(value as f64 * factor);
+
+                if result.is_nan() {
+                    i128::from(value)
+                } else {
+                    result as i128
+                }
+            }
+            Self::Clamp(lower, upper) => i128::from(value.clamp(lower, upper)),
+        };
+        let saturated = result.clamp(min.into(), max.into());
+        Ok((
+            i64::try_from(saturated).unwrap_or(value),
+            saturated != result,
+        ))
+    }
+
+    /// Check that the operation can be applied.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if a clamp's lower bound exceeds its upper bound.
+    pub fn validate(self) -> Result<(), anyhow::Error> {
+        match self {
+            Self::Clamp(lower, upper) if lower > upper => Err(anyhow::Error::msg(format!(
+                "invalid range: lower bound {lower} exceeds upper bound {upper}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// The outcome of adjusting a field
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    /// The field is unset and was left as is.
+    Unset,
+    /// The field was adjusted.
+    Adjusted,
+    /// The result exceeded the field's range and was saturated.
+    Saturated,
+}
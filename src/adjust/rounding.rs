@@ -0,0 +1,19 @@
+/// How to round the result of a multiplication
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Rounding {
+    #[default]
+    Nearest,
+    Down,
+    Up,
+}
+
+impl Rounding {
+    #[must_use]
+    pub fn round(self, value: f64) -> f64 {
+        match self {
+            Self::Nearest => value.round(),
+            Self::Down => value.floor(),
+            Self::Up => value.ceil(),
+        }
+    }
+}
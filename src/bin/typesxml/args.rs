@@ -1,9 +1,17 @@
 mod action;
 mod arguments;
 mod field_value;
+mod format;
 mod functions;
+mod operation;
+mod selector;
 
 pub use action::Action;
 pub use arguments::Arguments;
-pub use field_value::{FieldValue, FlagValues};
-pub use functions::{read_types_or_exit, write_type_or_exit};
+pub use field_value::FieldValue;
+pub use format::Format;
+pub use functions::{
+    is_mission, print_type_or_exit, read_mission_or_exit, read_types_or_exit, write_type_or_exit,
+};
+pub use operation::Operation;
+pub use selector::Selector;
@@ -2,41 +2,94 @@ use crate::args::Arguments;
 use clap::Subcommand;
 
 mod add;
+mod check;
+mod convert;
+mod dedup;
+mod diff;
+mod economy;
+mod events;
+mod export;
 mod find;
 mod fix;
+mod import;
 mod merge;
+mod merge3;
 mod remove;
 mod set;
 mod show;
+mod spawnable;
 
 #[derive(Clone, Debug, Subcommand)]
 pub enum Action {
     #[command(long_about = "Add a new type")]
     Add(add::Add),
+    #[command(long_about = "Check the types for semantic errors")]
+    Check(check::Check),
+    #[command(long_about = "Convert the base file between XML, JSON, YAML and TOML")]
+    Convert(convert::Convert),
+    #[command(long_about = "Resolve types defined more than once")]
+    Dedup(dedup::Dedup),
+    #[command(long_about = "Show the differences between the base XML file and another XML file")]
+    Diff(diff::Diff),
+    #[command(
+        subcommand,
+        long_about = "List, register and unregister the files of a cfgeconomycore.xml file"
+    )]
+    Economy(economy::Economy),
+    #[command(
+        subcommand,
+        long_about = "Find, show and edit the events of an events.xml file"
+    )]
+    Events(events::EventAction),
+    #[command(long_about = "Export the types to another format")]
+    Export(export::Export),
     #[command(long_about = "Display the selected type's properties")]
     Find(find::Find),
     #[command(long_about = "Fix errors in the given file")]
     Fix(fix::Fix),
+    #[command(
+        long_about = "Import types from a CSV file, replacing existing types of the same name"
+    )]
+    Import(import::Import),
     #[command(long_about = "Merge an extension XML file into the base XML file")]
     Merge(merge::Merge),
+    #[command(
+        long_about = "Merge the changes between a base and an upstream XML file into the base XML file"
+    )]
+    Merge3(merge3::Merge3),
     #[command(long_about = "Remove an existing type")]
     Remove(remove::Remove),
     #[command(long_about = "Set the selected type's properties")]
     Set(set::Set),
     #[command(long_about = "Show the selected type")]
     Show(show::Show),
+    #[command(
+        subcommand,
+        long_about = "Show and edit the attachments and cargo of a cfgspawnabletypes.xml file"
+    )]
+    Spawnable(spawnable::Spawnable),
 }
 
 impl Action {
     pub fn run(&self, args: &Arguments) {
         match self {
             Self::Add(action) => action.run(args),
+            Self::Check(action) => action.run(args),
+            Self::Convert(action) => action.run(args),
+            Self::Dedup(action) => action.run(args),
+            Self::Diff(action) => action.run(args),
+            Self::Economy(action) => action.run(args),
+            Self::Events(action) => action.run(args),
+            Self::Export(action) => action.run(args),
             Self::Find(action) => action.run(args),
             Self::Fix(action) => action.run(args),
+            Self::Import(action) => action.run(args),
             Self::Merge(action) => action.run(args),
+            Self::Merge3(action) => action.run(args),
             Self::Remove(action) => action.run(args),
             Self::Set(action) => action.run(args),
             Self::Show(action) => action.run(args),
+            Self::Spawnable(action) => action.run(args),
         }
     }
 }
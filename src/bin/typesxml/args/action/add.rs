@@ -1,11 +1,36 @@
-use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
+use crate::args::{read_types_or_exit, write_type_or_exit, Arguments, FieldValue};
+use clap::builder::NonEmptyStringValueParser;
 use clap::Args;
+use std::process::exit;
+use typesxml::format::Format;
 use typesxml::Type;
 
 #[derive(Clone, Debug, Args)]
 pub struct Add {
-    #[arg(index = 1, name = "type")]
+    #[arg(index = 1, name = "type", value_parser = NonEmptyStringValueParser::new())]
     name: String,
+    #[arg(
+        long,
+        short,
+        conflicts_with = "template",
+        help = "Copy all fields from the given existing type"
+    )]
+    like: Option<String>,
+    #[arg(
+        long,
+        short,
+        help = "Copy all fields from the type in the given XML, JSON, YAML or TOML file"
+    )]
+    template: Option<String>,
+    #[command(subcommand)]
+    field_value: Option<FieldValue>,
+    #[arg(
+        long,
+        short,
+        value_name = "FIELD=VALUE",
+        help = "Set the given field, e.g. nominal=10, usages=\"Town Village\" or flags=\"crafted true\"; may be repeated"
+    )]
+    set: Vec<FieldValue>,
     #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
     output: Option<String>,
     #[arg(long, short, help = "Write result to the original file")]
@@ -15,7 +40,35 @@ pub struct Add {
 impl Add {
     pub fn run(&self, args: &Arguments) {
         let mut types = read_types_or_exit(args.file(), true);
-        types.add(Type::new(&self.name));
+
+        if types.contains(&self.name) {
+            eprintln!("Type already exists: {}", self.name);
+            exit(8);
+        }
+
+        let mut typ = if let Some(ref like) = self.like {
+            types.get(like).cloned().unwrap_or_else(|| {
+                eprintln!("No such type: {like}");
+                exit(4);
+            })
+        } else if let Some(ref template) = self.template {
+            Format::from_path(template)
+                .unwrap_or_default()
+                .read_type(template)
+                .unwrap_or_else(|error| {
+                    eprintln!("{template}\n{error}");
+                    exit(1);
+                })
+        } else {
+            Type::builder(&self.name).build().unwrap_or_else(|error| {
+                eprintln!("{error}");
+                exit(2);
+            })
+        };
+
+        typ.set_name(&self.name);
+        self.set_fields(&mut typ);
+        types.add(typ);
         write_type_or_exit(
             &types,
             if self.in_place {
@@ -25,4 +78,48 @@ impl Add {
             },
         );
     }
+
+    /// Apply the field given as subcommand followed by the fields given with `--set`.
+    fn set_fields(&self, typ: &mut Type) {
+        for field_value in self.field_value.iter().chain(&self.set) {
+            field_value.set(typ);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Add;
+    use clap::Parser;
+    use typesxml::Type;
+
+    #[derive(Parser)]
+    struct Command {
+        #[command(flatten)]
+        add: Add,
+    }
+
+    #[test]
+    fn sets_every_given_field() {
+        let command = Command::try_parse_from([
+            "add",
+            "Apple",
+            "--set",
+            "nominal=10",
+            "--set",
+            "usages=Town user:Coast",
+        ])
+        .unwrap();
+        let mut typ = Type::new("Apple");
+        command.add.set_fields(&mut typ);
+        assert_eq!(typ.get_nominal(), Some(10));
+        assert_eq!(
+            typ.get_usages()
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["Town", "user:Coast"]
+        );
+    }
 }
@@ -0,0 +1,105 @@
+use crate::args::{is_mission, read_mission_or_exit, read_types_or_exit, Arguments};
+use clap::Args;
+use std::process::exit;
+use typesxml::limits::{LimitsDefinition, UserLimitsDefinition};
+use typesxml::validate::{validate, Diagnostic, Severity};
+
+#[derive(Clone, Debug, Args)]
+pub struct Check {
+    #[arg(long, short, help = "Treat warnings as errors")]
+    deny_warnings: bool,
+    #[arg(
+        long,
+        short,
+        help = "Check categories, tags, usages and values against the given cfglimitsdefinition.xml"
+    )]
+    limits: Option<String>,
+    #[arg(
+        long,
+        short,
+        requires = "limits",
+        help = "Check the user groupings in the given cfglimitsdefinitionuser.xml and the types' references to them"
+    )]
+    user_limits: Option<String>,
+}
+
+impl Check {
+    pub fn run(&self, args: &Arguments) {
+        if is_mission(args.file()) {
+            self.run_mission(args);
+            return;
+        }
+
+        let types = read_types_or_exit(args.file(), true);
+        let mut diagnostics = validate(&types);
+
+        if let Some(ref filename) = self.limits {
+            let limits = LimitsDefinition::read(filename).unwrap_or_else(|error| {
+                eprintln!("{filename}\n{error}");
+                exit(1);
+            });
+            diagnostics.extend(limits.validate(&types));
+
+            if let Some(ref filename) = self.user_limits {
+                let user_limits = UserLimitsDefinition::read(filename).unwrap_or_else(|error| {
+                    eprintln!("{filename}\n{error}");
+                    exit(1);
+                });
+                diagnostics.extend(user_limits.validate(&types));
+                diagnostics.extend(limits.validate_user(&user_limits));
+            }
+        }
+
+        for diagnostic in &diagnostics {
+            if diagnostic.position().is_some() {
+                println!("{}:{diagnostic}", args.file());
+            } else {
+                println!("{diagnostic}");
+            }
+        }
+
+        self.exit_on_failure(&diagnostics);
+    }
+
+    fn run_mission(&self, args: &Arguments) {
+        let mut mission = read_mission_or_exit(args.file());
+
+        if let Some(ref filename) = self.limits {
+            mission.load_limits(filename).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                exit(1);
+            });
+        }
+
+        if let Some(ref filename) = self.user_limits {
+            mission.load_user_limits(filename).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                exit(1);
+            });
+        }
+
+        let diagnostics = mission.validate();
+
+        for (path, diagnostic) in &diagnostics {
+            if diagnostic.position().is_some() {
+                println!("{}:{diagnostic}", path.display());
+            } else {
+                println!("{}: {diagnostic}", path.display());
+            }
+        }
+
+        self.exit_on_failure(diagnostics.iter().map(|(_, diagnostic)| diagnostic));
+    }
+
+    fn exit_on_failure<'diagnostic>(
+        &self,
+        diagnostics: impl IntoIterator<Item = &'diagnostic Diagnostic>,
+    ) {
+        if diagnostics
+            .into_iter()
+            .any(|diagnostic| self.deny_warnings || diagnostic.severity() == Severity::Error)
+        {
+            exit(5);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::args::{Arguments, Format};
+use clap::Args;
+use std::process::exit;
+use typesxml::format;
+
+#[derive(Clone, Debug, Args)]
+pub struct Convert {
+    #[arg(
+        long,
+        short,
+        value_enum,
+        help = "The format of the base file [default: derived from its extension]"
+    )]
+    from: Option<Format>,
+    #[arg(
+        long,
+        short,
+        value_enum,
+        help = "The format to convert to [default: derived from the output file's extension]"
+    )]
+    to: Option<Format>,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+}
+
+impl Convert {
+    pub fn run(&self, args: &Arguments) {
+        let from = self.from.map_or_else(
+            || format::Format::from_path(args.file()).unwrap_or_default(),
+            format::Format::from,
+        );
+        let to = self
+            .to
+            .map(format::Format::from)
+            .or_else(|| self.output.as_deref().and_then(format::Format::from_path))
+            .unwrap_or_else(|| {
+                eprintln!("Cannot determine the target format. Please specify it with --to.");
+                exit(2);
+            });
+        let types = from.read_types(args.file()).unwrap_or_else(|error| {
+            eprintln!("{}\n{error}", args.file());
+            exit(1);
+        });
+        let text = to.serialize_types(&types).unwrap_or_else(|error| {
+            eprintln!("Could not serialize {to}: {error}");
+            exit(3);
+        });
+
+        self.output.as_ref().map_or_else(
+            || println!("{}", text.trim_end()),
+            |filename| {
+                std::fs::write(filename, &text).unwrap_or_else(|error| {
+                    eprintln!("{filename}\n{error}");
+                    exit(3);
+                });
+            },
+        );
+    }
+}
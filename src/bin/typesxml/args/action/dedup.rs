@@ -0,0 +1,90 @@
+use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
+use clap::{Args, ValueEnum};
+use typesxml::{dedup, CaseSensitivity};
+
+#[derive(Clone, Debug, Args)]
+pub struct Dedup {
+    #[arg(
+        long,
+        short,
+        value_enum,
+        default_value_t = Policy::KeepLast,
+        help = "How to resolve types defined more than once"
+    )]
+    policy: Policy,
+    #[arg(
+        long,
+        short,
+        help = "Only treat types with exactly the same name as duplicates"
+    )]
+    exact: bool,
+    #[arg(
+        long,
+        short = 'n',
+        help = "Only report the duplicates without writing the result"
+    )]
+    dry_run: bool,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Policy {
+    #[value(help = "Keep the first copy")]
+    KeepFirst,
+    #[value(help = "Keep the last copy, which is the one the game uses")]
+    KeepLast,
+    #[value(help = "Override the fields of the first copy with those of the later ones")]
+    MergeFields,
+}
+
+impl Dedup {
+    pub fn run(&self, args: &Arguments) {
+        let mut types = read_types_or_exit(args.file(), true);
+        let case = if self.exact {
+            CaseSensitivity::Sensitive
+        } else {
+            CaseSensitivity::Insensitive
+        };
+        types.set_case_sensitivity(case);
+        let duplicates = if self.dry_run {
+            types.duplicates(case)
+        } else {
+            types.dedup(self.policy.into(), case)
+        };
+
+        for duplicate in &duplicates {
+            for span in types
+                .locate_all(duplicate.name())
+                .filter_map(|location| location.span())
+            {
+                eprintln!("{}:{}: {}", args.file(), span.start(), duplicate.name());
+            }
+
+            eprintln!("{duplicate}");
+        }
+
+        if !self.dry_run {
+            write_type_or_exit(
+                &types,
+                if self.in_place {
+                    Some(args.file())
+                } else {
+                    self.output.as_deref()
+                },
+            );
+        }
+    }
+}
+
+impl From<Policy> for dedup::Policy {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::KeepFirst => Self::KeepFirst,
+            Policy::KeepLast => Self::KeepLast,
+            Policy::MergeFields => Self::MergeFields,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::args::{read_types_or_exit, Arguments};
+use clap::{Args, ValueEnum};
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Diff {
+    #[arg(index = 1, help = "The XML file to compare the base XML file to")]
+    other: String,
+    #[arg(long, short, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Format {
+    Text,
+    Xml,
+    #[cfg(feature = "json")]
+    Json,
+}
+
+impl Diff {
+    pub fn run(&self, args: &Arguments) {
+        let diff =
+            read_types_or_exit(args.file(), true).diff(&read_types_or_exit(&self.other, true));
+
+        match self.format {
+            Format::Text => print!("{diff}"),
+            Format::Xml => diff.to_xml_patch().map_or_else(
+                |error| {
+                    eprintln!("Could not serialize XML: {error}");
+                    exit(3);
+                },
+                |xml| println!("{xml}"),
+            ),
+            #[cfg(feature = "json")]
+            Format::Json => serde_json::to_string_pretty(&diff).map_or_else(
+                |error| {
+                    eprintln!("Could not serialize JSON: {error}");
+                    exit(3);
+                },
+                |json| println!("{json}"),
+            ),
+        }
+    }
+}
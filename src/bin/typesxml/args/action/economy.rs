@@ -0,0 +1,88 @@
+use crate::args::Arguments;
+use clap::{Subcommand, ValueEnum};
+use std::path::Path;
+use std::process::exit;
+use typesxml::economycore::{self, EconomyCore};
+
+mod list;
+mod register;
+mod unregister;
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum Economy {
+    #[command(long_about = "List the registered files and report those missing on disk")]
+    List(list::List),
+    #[command(long_about = "Register a file in a <ce> folder")]
+    Register(register::Register),
+    #[command(long_about = "Remove a file's registration from a <ce> folder")]
+    Unregister(unregister::Unregister),
+}
+
+impl Economy {
+    pub fn run(&self, args: &Arguments) {
+        match self {
+            Self::List(action) => action.run(args),
+            Self::Register(action) => action.run(args),
+            Self::Unregister(action) => action.run(args),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Kind {
+    #[value(help = "A types.xml file")]
+    Types,
+    #[value(name = "spawnabletypes", help = "A cfgspawnabletypes.xml file")]
+    SpawnableTypes,
+    #[value(help = "An events.xml file")]
+    Events,
+}
+
+impl Kind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Types => "types",
+            Self::SpawnableTypes => "spawnabletypes",
+            Self::Events => "events",
+        }
+    }
+}
+
+/// Return the path of the `cfgeconomycore.xml` given directly or via its mission directory.
+pub fn economy_core_path(filename: &str) -> String {
+    let path = Path::new(filename);
+
+    if path.is_dir() {
+        path.join(economycore::FILE_NAME)
+            .to_string_lossy()
+            .into_owned()
+    } else {
+        filename.to_string()
+    }
+}
+
+/// Return the mission directory containing the given `cfgeconomycore.xml`.
+pub fn mission_root(filename: &str) -> &Path {
+    Path::new(filename)
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+}
+
+pub fn read_economy_core_or_exit(filename: &str) -> EconomyCore {
+    EconomyCore::read(filename).unwrap_or_else(|error| {
+        eprintln!("{filename}\n{error}");
+        exit(1);
+    })
+}
+
+pub fn write_economy_core_or_exit(economy_core: &EconomyCore, filename: Option<&str>) {
+    filename.map_or_else(
+        || print!("{economy_core}"),
+        |filename| {
+            economy_core.write(filename).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                exit(3);
+            });
+        },
+    );
+}
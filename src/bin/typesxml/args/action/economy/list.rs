@@ -0,0 +1,31 @@
+use crate::args::action::economy::{economy_core_path, mission_root, read_economy_core_or_exit};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct List {
+    #[arg(long, short, help = "Only list the files missing on disk")]
+    missing: bool,
+}
+
+impl List {
+    pub fn run(&self, args: &Arguments) {
+        let path = economy_core_path(args.file());
+        let economy_core = read_economy_core_or_exit(&path);
+        let missing = economy_core.missing(mission_root(&path));
+
+        for registration in economy_core.registrations() {
+            if missing.contains(&registration) {
+                println!("{registration}\tmissing");
+            } else if !self.missing {
+                println!("{registration}");
+            }
+        }
+
+        if !missing.is_empty() {
+            eprintln!("{} registered file(s) missing", missing.len());
+            exit(4);
+        }
+    }
+}
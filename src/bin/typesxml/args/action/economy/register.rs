@@ -0,0 +1,54 @@
+use crate::args::action::economy::{
+    economy_core_path, mission_root, read_economy_core_or_exit, write_economy_core_or_exit, Kind,
+};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+use typesxml::economycore::Registration;
+
+#[derive(Clone, Debug, Args)]
+pub struct Register {
+    #[arg(index = 1, help = "The folder relative to the mission directory")]
+    folder: String,
+    #[arg(index = 2, name = "file", help = "The file's name within the folder")]
+    name: String,
+    #[arg(long = "type", short = 't', value_enum, help = "The kind of file")]
+    kind: Kind,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Register {
+    pub fn run(&self, args: &Arguments) {
+        let path = economy_core_path(args.file());
+        let mut economy_core = read_economy_core_or_exit(&path);
+        let registration = Registration::new(&self.folder, &self.name, self.kind.as_str());
+
+        match economy_core.register(&registration) {
+            Ok(true) => (),
+            Ok(false) => {
+                eprintln!("Already registered: {}/{}", self.folder, self.name);
+                exit(8);
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                exit(2);
+            }
+        }
+
+        if !registration.path(mission_root(&path)).is_file() {
+            eprintln!("Warning: {registration} does not exist");
+        }
+
+        write_economy_core_or_exit(
+            &economy_core,
+            if self.in_place {
+                Some(path.as_str())
+            } else {
+                self.output.as_deref()
+            },
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::args::action::economy::{
+    economy_core_path, read_economy_core_or_exit, write_economy_core_or_exit,
+};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Unregister {
+    #[arg(index = 1, help = "The folder relative to the mission directory")]
+    folder: String,
+    #[arg(index = 2, name = "file", help = "The file's name within the folder")]
+    name: String,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Unregister {
+    pub fn run(&self, args: &Arguments) {
+        let path = economy_core_path(args.file());
+        let mut economy_core = read_economy_core_or_exit(&path);
+
+        if economy_core.unregister(&self.folder, &self.name).is_none() {
+            eprintln!("Not registered: {}/{}", self.folder, self.name);
+            exit(4);
+        }
+
+        write_economy_core_or_exit(
+            &economy_core,
+            if self.in_place {
+                Some(path.as_str())
+            } else {
+                self.output.as_deref()
+            },
+        );
+    }
+}
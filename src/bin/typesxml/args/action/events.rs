@@ -0,0 +1,152 @@
+use crate::args::Arguments;
+use clap::{ArgGroup, Args, Subcommand, ValueEnum};
+use regex::Regex;
+use serde_rw::ToXml;
+use std::process::exit;
+use typesxml::events::{self, Event, Events};
+
+mod add;
+mod check;
+mod field_value;
+mod find;
+mod remove;
+mod set;
+mod show;
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum EventAction {
+    #[command(long_about = "Add a new event")]
+    Add(add::Add),
+    #[command(long_about = "Check the events against their spawn positions")]
+    Check(check::Check),
+    #[command(long_about = "Display the properties of the events matching the regex")]
+    Find(find::Find),
+    #[command(long_about = "Remove existing events")]
+    Remove(remove::Remove),
+    #[command(long_about = "Set the selected events' properties")]
+    Set(set::Set),
+    #[command(long_about = "Show the selected event")]
+    Show(show::Show),
+}
+
+impl EventAction {
+    pub fn run(&self, args: &Arguments) {
+        match self {
+            Self::Add(action) => action.run(args),
+            Self::Check(action) => action.run(args),
+            Self::Find(action) => action.run(args),
+            Self::Remove(action) => action.run(args),
+            Self::Set(action) => action.run(args),
+            Self::Show(action) => action.run(args),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+#[command(group(ArgGroup::new("selector").required(true).args(["event", "regex"])))]
+pub struct Selector {
+    #[arg(index = 1, name = "event")]
+    name: Option<String>,
+    #[arg(long, short, help = "Select all events whose name matches the regex")]
+    regex: Option<Regex>,
+}
+
+impl Selector {
+    /// Return the name if a single event is selected by its name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn matches(&self, event: &Event) -> bool {
+        self.name
+            .as_ref()
+            .is_none_or(|name| event.get_name().eq_ignore_ascii_case(name))
+            && self
+                .regex
+                .as_ref()
+                .is_none_or(|regex| regex.is_match(event.get_name()))
+    }
+
+    pub fn report_no_match(&self) {
+        self.name.as_ref().map_or_else(
+            || eprintln!("No matching events"),
+            |name| eprintln!("No such event: {name}"),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Position {
+    #[value(help = "At the positions listed in cfgeventspawns.xml")]
+    Fixed,
+    #[value(help = "Around the players")]
+    Player,
+    #[value(help = "Anywhere on the map")]
+    Uniform,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Limit {
+    #[value(help = "Limit the amounts of each child")]
+    Child,
+    #[value(help = "Limit the amounts of the children and of the event")]
+    Mixed,
+    #[value(help = "Limit the amounts as defined by the event's script")]
+    Custom,
+    #[value(help = "Limit the amount of the event")]
+    Parent,
+}
+
+impl From<Position> for events::Position {
+    fn from(position: Position) -> Self {
+        match position {
+            Position::Fixed => Self::Fixed,
+            Position::Player => Self::Player,
+            Position::Uniform => Self::Uniform,
+        }
+    }
+}
+
+impl From<Limit> for events::Limit {
+    fn from(limit: Limit) -> Self {
+        match limit {
+            Limit::Child => Self::Child,
+            Limit::Mixed => Self::Mixed,
+            Limit::Custom => Self::Custom,
+            Limit::Parent => Self::Parent,
+        }
+    }
+}
+
+pub fn read_events_or_exit(filename: &str) -> Events {
+    Events::read(filename).unwrap_or_else(|error| {
+        eprintln!("{filename}\n{error}");
+        exit(1);
+    })
+}
+
+pub fn write_events_or_exit(events: &Events, filename: Option<&str>) {
+    filename.map_or_else(
+        || println!("{events}"),
+        |filename| {
+            events.write(filename).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                exit(3);
+            });
+        },
+    );
+}
+
+pub fn print_event_or_exit(event: &Event, xml: bool) {
+    if xml {
+        println!(
+            "{}",
+            event.to_xml_pretty(' ', 4).unwrap_or_else(|error| {
+                eprintln!("Could not serialize {}: {error}", event.get_name());
+                exit(3);
+            })
+        );
+    } else {
+        println!("{event}");
+    }
+}
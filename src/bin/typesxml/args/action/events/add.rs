@@ -0,0 +1,57 @@
+use crate::args::action::events::field_value::FieldValue;
+use crate::args::action::events::{read_events_or_exit, write_events_or_exit};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+use typesxml::events::Event;
+
+#[derive(Clone, Debug, Args)]
+pub struct Add {
+    #[arg(index = 1, name = "event")]
+    name: String,
+    #[arg(long, short, help = "Copy all fields from the given existing event")]
+    like: Option<String>,
+    #[command(subcommand)]
+    field_value: Option<FieldValue>,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Add {
+    pub fn run(&self, args: &Arguments) {
+        let mut events = read_events_or_exit(args.file());
+
+        if events.contains(&self.name) {
+            eprintln!("Event already exists: {}", self.name);
+            exit(8);
+        }
+
+        let mut event = self.like.as_ref().map_or_else(
+            || Event::new(&self.name),
+            |like| {
+                events.get(like).cloned().unwrap_or_else(|| {
+                    eprintln!("No such event: {like}");
+                    exit(4);
+                })
+            },
+        );
+
+        event.set_name(&self.name);
+
+        if let Some(ref field_value) = self.field_value {
+            field_value.set(&mut event);
+        }
+
+        events.add(event);
+        write_events_or_exit(
+            &events,
+            if self.in_place {
+                Some(args.file())
+            } else {
+                self.output.as_deref()
+            },
+        );
+    }
+}
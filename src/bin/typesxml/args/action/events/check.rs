@@ -0,0 +1,40 @@
+use crate::args::action::events::read_events_or_exit;
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+use typesxml::eventspawns::EventSpawns;
+use typesxml::validate::Severity;
+
+#[derive(Clone, Debug, Args)]
+pub struct Check {
+    #[arg(
+        long,
+        short,
+        help = "Check that the active events have positions in the given cfgeventspawns.xml and vice versa"
+    )]
+    spawns: String,
+    #[arg(long, short, help = "Treat warnings as errors")]
+    deny_warnings: bool,
+}
+
+impl Check {
+    pub fn run(&self, args: &Arguments) {
+        let events = read_events_or_exit(args.file());
+        let spawns = EventSpawns::read(&self.spawns).unwrap_or_else(|error| {
+            eprintln!("{}\n{error}", self.spawns);
+            exit(1);
+        });
+        let diagnostics = spawns.validate(&events);
+
+        for diagnostic in &diagnostics {
+            println!("{diagnostic}");
+        }
+
+        if diagnostics
+            .iter()
+            .any(|diagnostic| self.deny_warnings || diagnostic.severity() == Severity::Error)
+        {
+            exit(5);
+        }
+    }
+}
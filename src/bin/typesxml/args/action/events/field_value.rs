@@ -0,0 +1,143 @@
+use crate::args::action::events::{Limit, Position};
+use clap::{ArgAction, Subcommand};
+use typesxml::events::{Child, Event};
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum FieldValue {
+    #[command(long_about = "The event's name.")]
+    Name { name: String },
+    #[command(long_about = "Target amount of the event's spawns on the server.")]
+    Nominal { nominal: u32 },
+    #[command(long_about = "Minimum amount of the event's spawns on the server.")]
+    Min { min: u32 },
+    #[command(long_about = "Maximum amount of the event's spawns on the server.")]
+    Max { max: u32 },
+    #[command(long_about = "Despawn time in seconds.")]
+    Lifetime { lifetime: u32 },
+    #[command(long_about = "Respawn cooldown in seconds.")]
+    Restock { restock: u32 },
+    #[command(long_about = "Minimum distance in meters to players for the event to spawn.")]
+    Saferadius { saferadius: u32 },
+    #[command(long_about = "Minimum distance in meters between two spawns of the event.")]
+    Distanceradius { distanceradius: u32 },
+    #[command(
+        long_about = "Distance in meters to players within which the event is not cleaned up."
+    )]
+    Cleanupradius { cleanupradius: u32 },
+    #[command(long_about = "Secondary event spawned along with this one, e.g. infected.")]
+    Secondary { secondary: Option<String> },
+    #[command(long_about = "How the game treats the event's spawned items.")]
+    Flags {
+        #[command(subcommand)]
+        flags: FlagValues,
+    },
+    #[command(long_about = "Where the event spawns.")]
+    Position {
+        #[arg(value_enum)]
+        position: Position,
+    },
+    #[command(long_about = "Which amounts the nominal and min values limit.")]
+    Limit {
+        #[arg(value_enum)]
+        limit: Limit,
+    },
+    #[command(long_about = "Whether the event spawns at all.")]
+    Active {
+        #[arg(action = ArgAction::Set)]
+        active: bool,
+    },
+    #[command(long_about = "Add a class spawned by the event or update its amounts.")]
+    Child {
+        #[arg(name = "type")]
+        typ: String,
+        #[arg(long, default_value_t = 1)]
+        min: u32,
+        #[arg(long, default_value_t = 1)]
+        max: u32,
+        #[arg(long, default_value_t = 0)]
+        lootmin: u32,
+        #[arg(long, default_value_t = 0)]
+        lootmax: u32,
+    },
+    #[command(long_about = "Remove a class spawned by the event.")]
+    RemoveChild {
+        #[arg(name = "type")]
+        typ: String,
+    },
+}
+
+impl FieldValue {
+    pub fn set(&self, event: &mut Event) {
+        match self {
+            Self::Name { name } => event.set_name(name),
+            Self::Nominal { nominal } => event.set_nominal(*nominal),
+            Self::Min { min } => event.set_min(*min),
+            Self::Max { max } => event.set_max(*max),
+            Self::Lifetime { lifetime } => event.set_lifetime(*lifetime),
+            Self::Restock { restock } => event.set_restock(*restock),
+            Self::Saferadius { saferadius } => event.set_saferadius(*saferadius),
+            Self::Distanceradius { distanceradius } => event.set_distanceradius(*distanceradius),
+            Self::Cleanupradius { cleanupradius } => event.set_cleanupradius(*cleanupradius),
+            Self::Secondary { secondary } => event.set_secondary(secondary.as_deref()),
+            Self::Flags { flags } => flags.set(event),
+            Self::Position { position } => event.set_position((*position).into()),
+            Self::Limit { limit } => event.set_limit((*limit).into()),
+            Self::Active { active } => event.set_active(*active),
+            Self::Child {
+                typ,
+                min,
+                max,
+                lootmin,
+                lootmax,
+            } => {
+                let children = event.mut_children();
+                let position = children
+                    .iter()
+                    .position(|child| child.get_type().eq_ignore_ascii_case(typ))
+                    .unwrap_or_else(|| {
+                        children.push(Child::new(typ));
+                        children.len() - 1
+                    });
+                let child = &mut children[position];
+                child.set_min(*min);
+                child.set_max(*max);
+                child.set_lootmin(*lootmin);
+                child.set_lootmax(*lootmax);
+            }
+            Self::RemoveChild { typ } => event
+                .mut_children()
+                .retain(|child| !child.get_type().eq_ignore_ascii_case(typ)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum FlagValues {
+    #[command(long_about = "The spawned items can be deleted by the central economy.")]
+    Deletable {
+        #[arg(action = ArgAction::Set)]
+        deletable: bool,
+    },
+    #[command(long_about = "Randomize the spawned items' initial quantity.")]
+    InitRandom {
+        #[arg(action = ArgAction::Set)]
+        init_random: bool,
+    },
+    #[command(long_about = "Remove the spawned items once they are ruined.")]
+    RemoveDamaged {
+        #[arg(action = ArgAction::Set)]
+        remove_damaged: bool,
+    },
+}
+
+impl FlagValues {
+    fn set(&self, event: &mut Event) {
+        match self {
+            Self::Deletable { deletable } => event.mut_flags().set_deletable(*deletable),
+            Self::InitRandom { init_random } => event.mut_flags().set_init_random(*init_random),
+            Self::RemoveDamaged { remove_damaged } => {
+                event.mut_flags().set_remove_damaged(*remove_damaged);
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::args::action::events::{print_event_or_exit, read_events_or_exit};
+use crate::args::Arguments;
+use clap::Args;
+use regex::Regex;
+
+#[derive(Clone, Debug, Args)]
+pub struct Find {
+    #[arg(index = 1, name = "event")]
+    regex: Regex,
+    #[arg(long, short, help = "Show events as XML")]
+    xml: bool,
+}
+
+impl Find {
+    pub fn run(&self, args: &Arguments) {
+        for event in read_events_or_exit(args.file())
+            .events()
+            .filter(|event| self.regex.is_match(event.get_name()))
+        {
+            print_event_or_exit(event, self.xml);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::args::action::events::{read_events_or_exit, write_events_or_exit, Selector};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Remove {
+    #[command(flatten)]
+    selector: Selector,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Remove {
+    pub fn run(&self, args: &Arguments) {
+        let mut events = read_events_or_exit(args.file());
+        let removed = events.retain(|event| !self.selector.matches(event));
+
+        if removed.is_empty() {
+            self.selector.report_no_match();
+            exit(4);
+        }
+
+        if self.selector.name().is_none() {
+            eprintln!("Removed {} events", removed.len());
+        }
+
+        write_events_or_exit(
+            &events,
+            if self.in_place {
+                Some(args.file())
+            } else {
+                self.output.as_deref()
+            },
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::args::action::events::field_value::FieldValue;
+use crate::args::action::events::{read_events_or_exit, write_events_or_exit, Selector};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Set {
+    #[command(flatten)]
+    selector: Selector,
+    #[command(subcommand)]
+    field_value: FieldValue,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Set {
+    pub fn run(&self, args: &Arguments) {
+        let mut events = read_events_or_exit(args.file());
+        let mut matches = 0;
+        let mut modified = 0;
+
+        for event in events
+            .mut_events()
+            .filter(|event| self.selector.matches(event))
+        {
+            let original = event.clone();
+            self.field_value.set(event);
+            matches += 1;
+
+            if *event != original {
+                modified += 1;
+            }
+        }
+
+        if matches == 0 {
+            self.selector.report_no_match();
+            exit(4);
+        }
+
+        write_events_or_exit(
+            &events,
+            if self.in_place {
+                Some(args.file())
+            } else {
+                self.output.as_deref()
+            },
+        );
+
+        if self.selector.name().is_none() {
+            eprintln!("Modified {modified} of {matches} matching events");
+        }
+    }
+}
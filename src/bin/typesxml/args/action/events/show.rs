@@ -0,0 +1,23 @@
+use crate::args::action::events::{print_event_or_exit, read_events_or_exit};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Show {
+    #[arg(index = 1, name = "event")]
+    name: String,
+    #[arg(long, short, help = "Show event as XML")]
+    xml: bool,
+}
+
+impl Show {
+    pub fn run(&self, args: &Arguments) {
+        let events = read_events_or_exit(args.file());
+        let Some(event) = events.get(&self.name) else {
+            eprintln!("No such event: {}", self.name);
+            exit(4);
+        };
+        print_event_or_exit(event, self.xml);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::args::{read_types_or_exit, Arguments};
+use clap::{Args, ValueEnum};
+use std::fs::File;
+use std::io::{stdout, Write};
+use std::process::exit;
+use typesxml::filter::Filter;
+
+#[derive(Clone, Debug, Args)]
+pub struct Export {
+    #[arg(long, short, value_enum, default_value_t = Format::Csv)]
+    format: Format,
+    #[arg(
+        long = "where",
+        short = 'w',
+        help = "Only export types matching the filter expression"
+    )]
+    filter: Option<Filter>,
+    #[arg(long, short, default_value_t = ',', help = "The CSV field delimiter")]
+    delimiter: char,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Format {
+    Csv,
+}
+
+impl Export {
+    pub fn run(&self, args: &Arguments) {
+        let types = read_types_or_exit(args.file(), true);
+        let writer: Box<dyn Write> = match self.output {
+            Some(ref filename) => Box::new(File::create(filename).unwrap_or_else(|error| {
+                eprintln!("{filename}\n{error}");
+                exit(3);
+            })),
+            None => Box::new(stdout()),
+        };
+
+        match self.format {
+            Format::Csv => typesxml::csv::to_csv(
+                types.types().filter(|typ| {
+                    self.filter
+                        .as_ref()
+                        .is_none_or(|filter| filter.matches(typ))
+                }),
+                writer,
+                delimiter_or_exit(self.delimiter),
+            ),
+        }
+        .unwrap_or_else(|error| {
+            eprintln!("{error}");
+            exit(3);
+        });
+    }
+}
+
+pub fn delimiter_or_exit(delimiter: char) -> u8 {
+    u8::try_from(delimiter).unwrap_or_else(|_| {
+        eprintln!("Delimiter must be an ASCII character: {delimiter}");
+        exit(2);
+    })
+}
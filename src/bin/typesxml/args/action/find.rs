@@ -1,32 +1,107 @@
-use crate::args::{read_types_or_exit, Arguments};
-use clap::Args;
+use crate::args::{
+    is_mission, print_type_or_exit, read_mission_or_exit, read_types_or_exit, Arguments, Format,
+};
+use clap::{ArgGroup, Args};
 use regex::Regex;
-use serde_rw::ToXml;
+use typesxml::filter::Filter;
+use typesxml::Type;
 
 #[derive(Clone, Debug, Args)]
+#[command(group(ArgGroup::new("selector").required(true).multiple(true).args(["type", "filter"])))]
 pub struct Find {
     #[arg(index = 1, name = "type")]
-    regex: Regex,
-    #[arg(long, short, help = "Show type as XML")]
+    regex: Option<Regex>,
+    #[arg(
+        long = "where",
+        short = 'w',
+        help = "Only show types matching the filter expression"
+    )]
+    filter: Option<Filter>,
+    #[arg(long, short, help = "Show type as XML", conflicts_with = "format")]
     xml: bool,
+    #[arg(long, short, value_enum, help = "Show types in the given format")]
+    format: Option<Format>,
+    #[arg(
+        long,
+        short,
+        conflicts_with_all = ["xml", "format"],
+        help = "Only show the names of the types and where they are located in the file"
+    )]
+    locations: bool,
 }
 
 impl Find {
     pub fn run(&self, args: &Arguments) {
-        for typ in read_types_or_exit(args.file(), true)
+        if is_mission(args.file()) {
+            self.run_mission(args);
+            return;
+        }
+
+        let types = read_types_or_exit(args.file(), true);
+
+        for (position, typ) in types
             .types()
-            .filter(|typ| self.regex.is_match(typ.get_name()))
+            .enumerate()
+            .filter(|(_, typ)| self.matches(typ))
         {
-            if self.xml {
-                typ.to_xml_pretty(' ', 4).map_or_else(
-                    |_| eprintln!("Could not serialize XML"),
-                    |xml| {
-                        println!("{xml}");
-                    },
-                );
+            if self.locations {
+                types
+                    .locate_at(position)
+                    .and_then(|location| location.span())
+                    .map_or_else(
+                        || println!("{}: {}", args.file(), typ.get_name()),
+                        |span| println!("{}:{}: {}", args.file(), span.start(), typ.get_name()),
+                    );
             } else {
-                println!("{typ}");
+                print_type_or_exit(typ, self.format());
             }
         }
     }
+
+    fn run_mission(&self, args: &Arguments) {
+        let mission = read_mission_or_exit(args.file());
+
+        for definition in mission
+            .types()
+            .into_iter()
+            .filter(|definition| self.matches(definition.item()))
+        {
+            let typ = definition.item();
+            let path = definition.path().display();
+
+            if self.locations {
+                definition
+                    .file()
+                    .content()
+                    .locate_at(definition.position())
+                    .and_then(|location| location.span())
+                    .map_or_else(
+                        || println!("{path}: {}", typ.get_name()),
+                        |span| println!("{path}:{}: {}", span.start(), typ.get_name()),
+                    );
+            } else if self.format().is_none() {
+                println!("{typ}\nfile    :\t{path}");
+            } else {
+                print_type_or_exit(typ, self.format());
+            }
+        }
+    }
+
+    fn matches(&self, typ: &Type) -> bool {
+        self.regex
+            .as_ref()
+            .is_none_or(|regex| regex.is_match(typ.get_name()))
+            && self
+                .filter
+                .as_ref()
+                .is_none_or(|filter| filter.matches(typ))
+    }
+
+    const fn format(&self) -> Option<Format> {
+        if self.xml {
+            Some(Format::Xml)
+        } else {
+            self.format
+        }
+    }
 }
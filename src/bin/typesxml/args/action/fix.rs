@@ -1,12 +1,36 @@
-use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
+use crate::args::{write_type_or_exit, Arguments};
 use clap::Args;
+use std::process::exit;
+use typesxml::Types;
 
 #[derive(Clone, Debug, Args)]
-pub struct Fix;
+pub struct Fix {
+    #[arg(
+        long,
+        short = 'n',
+        help = "Only report what would be fixed without writing the file"
+    )]
+    dry_run: bool,
+}
 
 impl Fix {
-    #[allow(clippy::unused_self)]
     pub fn run(&self, args: &Arguments) {
-        write_type_or_exit(&read_types_or_exit(args.file(), false), Some(args.file()));
+        let (types, recoveries) = Types::read_gracefully_with_recoveries(args.file())
+            .unwrap_or_else(|error| {
+                eprintln!("{}\n{error}", args.file());
+                exit(1);
+            });
+
+        for recovery in &recoveries {
+            if recovery.position().is_some() {
+                eprintln!("{}:{recovery}", args.file());
+            } else {
+                eprintln!("{}: {recovery}", args.file());
+            }
+        }
+
+        if !self.dry_run {
+            write_type_or_exit(&types, Some(args.file()));
+        }
     }
 }
@@ -0,0 +1,57 @@
+use crate::args::action::export::delimiter_or_exit;
+use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
+use clap::Args;
+use std::fs::File;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Import {
+    #[arg(index = 1, help = "The CSV file to import types from")]
+    csv_file: String,
+    #[arg(long, short, default_value_t = ',', help = "The CSV field delimiter")]
+    delimiter: char,
+    #[arg(
+        long,
+        short,
+        help = "Import the valid rows even if other rows are invalid"
+    )]
+    skip_invalid: bool,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Import {
+    pub fn run(&self, args: &Arguments) {
+        let mut types = read_types_or_exit(args.file(), true);
+        let import = File::open(&self.csv_file)
+            .map_err(anyhow::Error::from)
+            .and_then(|file| {
+                typesxml::csv::from_csv(file, delimiter_or_exit(self.delimiter), &types)
+            })
+            .unwrap_or_else(|error| {
+                eprintln!("{}\n{error}", self.csv_file);
+                exit(1);
+            });
+
+        for error in import.errors() {
+            eprintln!("{}: {error}", self.csv_file);
+        }
+
+        if !import.errors().is_empty() && !self.skip_invalid {
+            exit(7);
+        }
+
+        import.apply(&mut types);
+
+        write_type_or_exit(
+            &types,
+            if self.in_place {
+                Some(args.file())
+            } else {
+                self.output.as_deref()
+            },
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
-use clap::Args;
+use clap::{Args, ValueEnum};
+use typesxml::merge;
 
 #[derive(Clone, Debug, Args)]
 pub struct Merge {
@@ -7,13 +8,77 @@ pub struct Merge {
     extension: String,
     #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
     output: Option<String>,
+    #[arg(
+        long,
+        short,
+        value_enum,
+        default_value_t = Strategy::ExtensionWins,
+        help = "How to resolve types present in both files"
+    )]
+    strategy: Strategy,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Order::Sort,
+        help = "The order of the merged types"
+    )]
+    order: Order,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Strategy {
+    #[value(help = "Keep the base's types")]
+    BaseWins,
+    #[value(help = "Replace the base's types with the extension's")]
+    ExtensionWins,
+    #[value(
+        help = "Override only the fields present in the extension, which may omit mandatory fields; new types take the defaults of add for them"
+    )]
+    Overlay,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Order {
+    #[value(help = "Keep the base's order and append new types")]
+    Preserve,
+    #[value(help = "Sort all types by name")]
+    Sort,
 }
 
 impl Merge {
     pub fn run(&self, args: &Arguments) {
-        write_type_or_exit(
-            &(read_types_or_exit(args.file(), true) + read_types_or_exit(&self.extension, true)),
-            self.output.as_deref(),
+        let merge = read_types_or_exit(args.file(), true).merge(
+            &read_types_or_exit(&self.extension, !matches!(self.strategy, Strategy::Overlay)),
+            self.strategy.into(),
+            self.order.into(),
         );
+        write_type_or_exit(merge.types(), self.output.as_deref());
+
+        for name in merge.overridden() {
+            eprintln!("overridden: {name}");
+        }
+
+        for name in merge.ignored() {
+            eprintln!("ignored: {name}");
+        }
+    }
+}
+
+impl From<Strategy> for merge::Strategy {
+    fn from(strategy: Strategy) -> Self {
+        match strategy {
+            Strategy::BaseWins => Self::BaseWins,
+            Strategy::ExtensionWins => Self::ExtensionWins,
+            Strategy::Overlay => Self::Overlay,
+        }
+    }
+}
+
+impl From<Order> for merge::Order {
+    fn from(order: Order) -> Self {
+        match order {
+            Order::Preserve => Self::Preserve,
+            Order::Sort => Self::Sort,
+        }
     }
 }
@@ -0,0 +1,46 @@
+use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
+use clap::Args;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Merge3 {
+    #[arg(
+        index = 1,
+        help = "The common ancestor, e.g. the previous vanilla XML file"
+    )]
+    base: String,
+    #[arg(
+        index = 2,
+        help = "The upstream XML file, e.g. the current vanilla XML file"
+    )]
+    theirs: String,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Merge3 {
+    pub fn run(&self, args: &Arguments) {
+        let merge = read_types_or_exit(args.file(), true).merge3(
+            &read_types_or_exit(&self.base, true),
+            &read_types_or_exit(&self.theirs, true),
+        );
+        write_type_or_exit(
+            merge.types(),
+            if self.in_place {
+                Some(args.file())
+            } else {
+                self.output.as_deref()
+            },
+        );
+
+        for conflict in merge.conflicts() {
+            eprintln!("conflict: {conflict}");
+        }
+
+        if !merge.conflicts().is_empty() {
+            exit(6);
+        }
+    }
+}
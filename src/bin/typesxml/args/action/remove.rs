@@ -1,10 +1,10 @@
-use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
+use crate::args::{read_types_or_exit, write_type_or_exit, Arguments, Selector};
 use clap::Args;
 
 #[derive(Clone, Debug, Args)]
 pub struct Remove {
-    #[arg(index = 1, name = "type")]
-    name: String,
+    #[command(flatten)]
+    selector: Selector,
     #[arg(long, short)]
     output: Option<String>,
     #[arg(long, short)]
@@ -14,7 +14,14 @@ pub struct Remove {
 impl Remove {
     pub fn run(&self, args: &Arguments) {
         let mut types = read_types_or_exit(args.file(), true);
-        types.remove(&self.name);
+
+        if let Some(name) = self.selector.name() {
+            types.remove(name);
+        } else {
+            let removed = types.retain(|typ| !self.selector.matches(typ));
+            eprintln!("Removed {} types", removed.len());
+        }
+
         write_type_or_exit(
             &types,
             if self.in_place {
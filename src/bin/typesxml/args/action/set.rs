@@ -1,12 +1,11 @@
-use crate::args::FieldValue;
-use crate::args::{read_types_or_exit, write_type_or_exit, Arguments};
+use crate::args::{read_types_or_exit, write_type_or_exit, Arguments, FieldValue, Selector};
 use clap::Args;
 use std::process::exit;
 
 #[derive(Clone, Debug, Args)]
 pub struct Set {
-    #[arg(index = 1, name = "type")]
-    name: String,
+    #[command(flatten)]
+    selector: Selector,
     #[command(subcommand)]
     field_value: FieldValue,
     #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
@@ -18,16 +17,31 @@ pub struct Set {
 impl Set {
     pub fn run(&self, args: &Arguments) {
         let mut types = read_types_or_exit(args.file(), true);
-        types
-            .mut_types()
-            .find(|typ| typ.get_name().to_ascii_lowercase() == self.name.to_ascii_lowercase())
-            .map_or_else(
-                || {
-                    eprintln!("No such type: {}", self.name);
-                    exit(4);
-                },
-                |typ| self.field_value.set(typ),
-            );
+        let mut matches = 0;
+        let mut modified = 0;
+
+        if let Some(name) = self.selector.name() {
+            if let Some(mut typ) = types.get_mut(name) {
+                self.field_value.set(&mut typ);
+                matches += 1;
+            }
+        } else {
+            for typ in types.mut_types().filter(|typ| self.selector.matches(typ)) {
+                let original = typ.clone();
+                self.field_value.set(typ);
+                matches += 1;
+
+                if *typ != original {
+                    modified += 1;
+                }
+            }
+        }
+
+        if matches == 0 {
+            self.selector.report_no_match();
+            exit(4);
+        }
+
         write_type_or_exit(
             &types,
             if self.in_place {
@@ -36,5 +50,9 @@ impl Set {
                 self.output.as_deref()
             },
         );
+
+        if self.selector.name().is_none() {
+            eprintln!("Modified {modified} of {matches} matching types");
+        }
     }
 }
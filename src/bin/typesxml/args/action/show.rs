@@ -1,36 +1,76 @@
-use crate::args::{read_types_or_exit, Arguments};
+use crate::args::{
+    is_mission, print_type_or_exit, read_mission_or_exit, read_types_or_exit, Arguments, Format,
+};
 use clap::Args;
-use serde_rw::ToXml;
 
 #[derive(Clone, Debug, Args)]
 pub struct Show {
     #[arg(index = 1, name = "type")]
     name: String,
-    #[arg(long, short, help = "Show type as XML")]
+    #[arg(long, short, help = "Show type as XML", conflicts_with = "format")]
     xml: bool,
+    #[arg(long, short, value_enum, help = "Show type in the given format")]
+    format: Option<Format>,
 }
 
 impl Show {
     pub fn run(&self, args: &Arguments) {
-        read_types_or_exit(args.file(), true)
-            .types()
-            .find(|typ| typ.get_name().to_ascii_lowercase() == self.name.to_ascii_lowercase())
-            .map_or_else(
-                || {
-                    eprintln!("No such type: {}", self.name);
-                },
-                |typ| {
-                    if self.xml {
-                        typ.to_xml_pretty(' ', 4).map_or_else(
-                            |_| eprintln!("Could not serialize XML"),
-                            |xml| {
-                                println!("{xml}");
-                            },
-                        );
-                    } else {
-                        println!("{typ}");
-                    }
-                },
+        if is_mission(args.file()) {
+            self.run_mission(args);
+            return;
+        }
+
+        let types = read_types_or_exit(args.file(), true);
+        let mut positions = types.positions(&self.name);
+        let Some(typ) = positions
+            .pop()
+            .and_then(|position| types.types().nth(position))
+        else {
+            eprintln!("No such type: {}", self.name);
+            return;
+        };
+
+        print_type_or_exit(typ, self.format());
+
+        for position in positions {
+            types
+                .locate_at(position)
+                .and_then(|location| location.span())
+                .map_or_else(
+                    || eprintln!("overridden: {}", args.file()),
+                    |span| eprintln!("overridden: {}:{}", args.file(), span.start()),
+                );
+        }
+    }
+
+    fn run_mission(&self, args: &Arguments) {
+        let mission = read_mission_or_exit(args.file());
+        let mut definitions = mission.type_definitions(&self.name);
+        let Some(definition) = definitions.pop() else {
+            eprintln!("No such type: {}", self.name);
+            return;
+        };
+
+        if self.format().is_none() {
+            println!(
+                "{}\nfile    :\t{}",
+                definition.item(),
+                definition.path().display()
             );
+        } else {
+            print_type_or_exit(definition.item(), self.format());
+        }
+
+        for overridden in definitions {
+            eprintln!("overridden: {}", overridden.path().display());
+        }
+    }
+
+    const fn format(&self) -> Option<Format> {
+        if self.xml {
+            Some(Format::Xml)
+        } else {
+            self.format
+        }
     }
 }
@@ -0,0 +1,99 @@
+use crate::args::Arguments;
+use clap::{Subcommand, ValueEnum};
+use std::process::exit;
+use std::str::FromStr;
+use typesxml::spawnabletypes::{Group, Item, SpawnableType, SpawnableTypes};
+
+mod add;
+mod remove;
+mod show;
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum Spawnable {
+    #[command(long_about = "Show what spawns on and in the given class")]
+    Show(show::Show),
+    #[command(long_about = "Add attachments or cargo to the given class")]
+    Add(add::Add),
+    #[command(long_about = "Remove attachments or cargo from the given class")]
+    Remove(remove::Remove),
+}
+
+impl Spawnable {
+    pub fn run(&self, args: &Arguments) {
+        match self {
+            Self::Show(action) => action.run(args),
+            Self::Add(action) => action.run(args),
+            Self::Remove(action) => action.run(args),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Slot {
+    #[value(help = "Items spawned on the class")]
+    Attachments,
+    #[value(help = "Items spawned in the class")]
+    Cargo,
+}
+
+impl Slot {
+    pub fn groups(self, typ: &mut SpawnableType) -> &mut Vec<Group> {
+        match self {
+            Self::Attachments => typ.mut_attachments(),
+            Self::Cargo => typ.mut_cargo(),
+        }
+    }
+}
+
+/// An item given as `NAME` or `NAME:CHANCE`
+#[derive(Clone, Debug)]
+pub struct ItemSpec(Item);
+
+impl FromStr for ItemSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, chance) = s.split_once(':').map_or(Ok((s, 1.0)), |(name, chance)| {
+            parse_chance(chance).map(|chance| (name, chance))
+        })?;
+
+        if name.is_empty() {
+            return Err("item name is empty".to_string());
+        }
+
+        Ok(Self(Item::new(name, chance)))
+    }
+}
+
+impl From<ItemSpec> for Item {
+    fn from(spec: ItemSpec) -> Self {
+        spec.0
+    }
+}
+
+pub fn parse_chance(chance: &str) -> Result<f32, String> {
+    chance
+        .parse::<f32>()
+        .ok()
+        .filter(|chance| (0.0..=1.0).contains(chance))
+        .ok_or_else(|| format!("chance {chance:?} is not a number between 0 and 1"))
+}
+
+pub fn read_spawnable_types_or_exit(filename: &str) -> SpawnableTypes {
+    SpawnableTypes::read(filename).unwrap_or_else(|error| {
+        eprintln!("{filename}\n{error}");
+        exit(1);
+    })
+}
+
+pub fn write_spawnable_types_or_exit(types: &SpawnableTypes, filename: Option<&str>) {
+    filename.map_or_else(
+        || println!("{types}"),
+        |filename| {
+            types.write(filename).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                exit(3);
+            });
+        },
+    );
+}
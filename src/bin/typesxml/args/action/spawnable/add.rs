@@ -0,0 +1,62 @@
+use crate::args::action::spawnable::{
+    parse_chance, read_spawnable_types_or_exit, write_spawnable_types_or_exit, ItemSpec, Slot,
+};
+use crate::args::Arguments;
+use clap::Args;
+use typesxml::spawnabletypes::Group;
+
+#[derive(Clone, Debug, Args)]
+pub struct Add {
+    #[arg(index = 1, name = "class")]
+    name: String,
+    #[arg(index = 2, value_enum)]
+    slot: Slot,
+    #[arg(
+        index = 3,
+        required_unless_present = "preset",
+        help = "The items to choose from, given as NAME or NAME:CHANCE"
+    )]
+    items: Vec<ItemSpec>,
+    #[arg(
+        long,
+        short,
+        value_parser = parse_chance,
+        help = "The chance of spawning any of the items"
+    )]
+    chance: Option<f32>,
+    #[arg(
+        long,
+        short,
+        conflicts_with_all = ["items", "chance"],
+        help = "Refer to the given preset from cfgrandompresets.xml instead of listing items"
+    )]
+    preset: Option<String>,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Add {
+    pub fn run(&self, args: &Arguments) {
+        let mut types = read_spawnable_types_or_exit(args.file());
+        let group = self.preset.as_deref().map_or_else(
+            || {
+                Group::new(
+                    self.chance,
+                    self.items.iter().cloned().map(Into::into).collect(),
+                )
+            },
+            Group::preset,
+        );
+        self.slot.groups(types.get_or_add(&self.name)).push(group);
+        write_spawnable_types_or_exit(
+            &types,
+            if self.in_place {
+                Some(args.file())
+            } else {
+                self.output.as_deref()
+            },
+        );
+    }
+}
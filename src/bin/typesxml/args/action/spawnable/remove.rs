@@ -0,0 +1,63 @@
+use crate::args::action::spawnable::{
+    read_spawnable_types_or_exit, write_spawnable_types_or_exit, Slot,
+};
+use crate::args::Arguments;
+use clap::Args;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Remove {
+    #[arg(index = 1, name = "class")]
+    name: String,
+    #[arg(index = 2, value_enum)]
+    slot: Slot,
+    #[arg(index = 3, help = "The item or preset to remove")]
+    entry: String,
+    #[arg(long, short, help = "Write result to the given file instead of STDOUT")]
+    output: Option<String>,
+    #[arg(long, short, help = "Write result to the original file")]
+    in_place: bool,
+}
+
+impl Remove {
+    pub fn run(&self, args: &Arguments) {
+        let mut types = read_spawnable_types_or_exit(args.file());
+        let Some(typ) = types.get_mut(&self.name) else {
+            eprintln!("No such class: {}", self.name);
+            exit(4);
+        };
+        let groups = self.slot.groups(typ);
+        let mut removed = 0;
+
+        for group in groups.iter_mut() {
+            if group
+                .get_preset()
+                .is_some_and(|preset| preset.eq_ignore_ascii_case(&self.entry))
+            {
+                group.set_preset(None);
+                removed += 1;
+            }
+
+            let items = group.get_items().len();
+            group
+                .mut_items()
+                .retain(|item| !item.get_name().eq_ignore_ascii_case(&self.entry));
+            removed += items - group.get_items().len();
+        }
+
+        if removed == 0 {
+            eprintln!("No such item or preset: {}", self.entry);
+            exit(4);
+        }
+
+        groups.retain(|group| !group.is_empty());
+        write_spawnable_types_or_exit(
+            &types,
+            if self.in_place {
+                Some(args.file())
+            } else {
+                self.output.as_deref()
+            },
+        );
+    }
+}
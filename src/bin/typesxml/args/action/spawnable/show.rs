@@ -0,0 +1,35 @@
+use crate::args::action::spawnable::read_spawnable_types_or_exit;
+use crate::args::Arguments;
+use clap::Args;
+use serde_rw::ToXml;
+use std::process::exit;
+
+#[derive(Clone, Debug, Args)]
+pub struct Show {
+    #[arg(index = 1, name = "class")]
+    name: String,
+    #[arg(long, short, help = "Show the class as XML")]
+    xml: bool,
+}
+
+impl Show {
+    pub fn run(&self, args: &Arguments) {
+        let types = read_spawnable_types_or_exit(args.file());
+        let Some(typ) = types.get(&self.name) else {
+            eprintln!("No such class: {}", self.name);
+            exit(4);
+        };
+
+        if self.xml {
+            println!(
+                "{}",
+                typ.to_xml_pretty(' ', 4).unwrap_or_else(|error| {
+                    eprintln!("{error}");
+                    exit(3);
+                })
+            );
+        } else {
+            println!("{typ}");
+        }
+    }
+}
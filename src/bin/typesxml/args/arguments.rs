@@ -6,7 +6,11 @@ const DESCRIPTION: &str = "Manipulate types.xml files for DayZ servers.";
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = DESCRIPTION, subcommand_value_name = "action", subcommand_help_heading = "Actions")]
 pub struct Arguments {
-    #[arg(index = 1, name = "xml_file", help = "The base XML file to operate on")]
+    #[arg(
+        index = 1,
+        name = "xml_file",
+        help = "The base XML file to operate on, or a mission directory for find, show, check and economy"
+    )]
     file: String,
     #[command(subcommand)]
     action: Action,
@@ -1,4 +1,9 @@
-use clap::Subcommand;
+use crate::args::Operation;
+use clap::{ArgAction, Parser, Subcommand};
+use std::iter::once;
+use std::process::exit;
+use std::str::FromStr;
+use typesxml::adjust::{Numeric, Outcome};
 use typesxml::{Named, Type};
 
 #[derive(Clone, Debug, Subcommand)]
@@ -6,7 +11,7 @@ pub enum FieldValue {
     #[command(long_about = "The item's name.")]
     Name { name: String },
     #[command(long_about = "Maximum amount of items of this type on the server.")]
-    Nominal { nominal: Option<u8> },
+    Nominal { nominal: Option<u32> },
     #[command(long_about = "Despawn time in seconds.")]
     Lifetime { lifetime: u32 },
     #[command(long_about = "Respawn cooldown in seconds.")]
@@ -14,7 +19,7 @@ pub enum FieldValue {
     #[command(
         long_about = "Minimum amount for this item to spawn. Must be less than or equal to nominal."
     )]
-    Min { min: u8 },
+    Min { min: u32 },
     #[command(
         long_about = "Minimum amount within the item e.g a water bottle or magazine. Use -1 if item doesnt hold a quantity of something."
     )]
@@ -32,12 +37,31 @@ pub enum FieldValue {
     },
     #[command(long_about = "Item category group.")]
     Category { category: Option<Named> },
-    #[command(long_about = "Area for where the item will spawn e.g farm. You can have up to 4.")]
-    Usages { usages: Option<Vec<Named>> },
+    #[command(long_about = "Placement tags for the item e.g. floor or shelves.")]
+    Tags { tags: Option<Vec<Named>> },
     #[command(
-        long_about = "Item value grouping. Tier1 (Spawn zones) through to Tier4 (Military)."
+        long_about = "Area for where the item will spawn e.g farm. You can have up to 4. Use user:NAME to refer to a group of cfglimitsdefinitionuser.xml."
     )]
-    Values { values: Option<Vec<Named>> },
+    Usages {
+        #[arg(value_parser = parse_named)]
+        usages: Option<Vec<Named>>,
+    },
+    #[command(
+        long_about = "Item value grouping. Tier1 (Spawn zones) through to Tier4 (Military). Use user:NAME to refer to a group of cfglimitsdefinitionuser.xml."
+    )]
+    Values {
+        #[arg(value_parser = parse_named)]
+        values: Option<Vec<Named>>,
+    },
+    #[command(
+        long_about = "Relatively update a numeric field. Unset fields are left unset and results are saturated to the field's range."
+    )]
+    Adjust {
+        #[arg(help = "One of nominal, lifetime, restock, min, quantmin, quantmax or cost")]
+        field: Numeric,
+        #[command(subcommand)]
+        operation: Operation,
+    },
 }
 
 impl FieldValue {
@@ -53,26 +77,78 @@ impl FieldValue {
             Self::Cost { cost } => typ.set_cost(*cost),
             Self::Flags { flags } => flags.set(typ),
             Self::Category { category } => typ.set_category(category.as_ref()),
+            Self::Tags { tags } => typ.set_tags(tags.as_deref()),
             Self::Usages { usages } => typ.set_usages(usages.as_deref()),
             Self::Values { values } => typ.set_values(values.as_deref()),
+            Self::Adjust { field, operation } => match typ.adjust(*field, operation.into()) {
+                Ok(Outcome::Saturated) => eprintln!("{}: {field} saturated", typ.get_name()),
+                Ok(_) => {}
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(2);
+                }
+            },
+        }
+    }
+}
+
+/// Parse `FIELD=VALUE` like the subcommand `FIELD VALUE`, with multiple values separated by whitespace.
+impl FromStr for FieldValue {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        #[derive(Parser)]
+        #[command(no_binary_name = true)]
+        struct Assignment {
+            #[command(subcommand)]
+            field_value: FieldValue,
         }
+
+        let (field, value) = text
+            .split_once('=')
+            .ok_or_else(|| format!("expected FIELD=VALUE: {text}"))?;
+        Assignment::try_parse_from(once(field).chain(value.split_whitespace()))
+            .map(|assignment| assignment.field_value)
+            .map_err(|error| {
+                let message = error.render().to_string();
+                let first = message.lines().next().unwrap_or_default();
+                first.trim_start_matches("error: ").to_string()
+            })
     }
 }
 
 #[derive(Clone, Debug, Subcommand)]
 pub enum FlagValues {
     #[command(long_about = "Includes items in cargo (backpacks, crates, cars).")]
-    CountInCargo { count_in_cargo: bool },
+    CountInCargo {
+        #[arg(action = ArgAction::Set)]
+        count_in_cargo: bool,
+    },
     #[command(long_about = "Includes items in cargo (tents, barrels, stashes etc).")]
-    CountInHoarder { count_in_hoarder: bool },
+    CountInHoarder {
+        #[arg(action = ArgAction::Set)]
+        count_in_hoarder: bool,
+    },
     #[command(long_about = "Includes items inside buildings.")]
-    CountInMap { count_in_map: bool },
+    CountInMap {
+        #[arg(action = ArgAction::Set)]
+        count_in_map: bool,
+    },
     #[command(long_about = "Includes items in players inventory.")]
-    CountInPlayer { count_in_player: bool },
+    CountInPlayer {
+        #[arg(action = ArgAction::Set)]
+        count_in_player: bool,
+    },
     #[command(long_about = "Item must be craftable by a player.")]
-    Crafted { crafted: bool },
+    Crafted {
+        #[arg(action = ArgAction::Set)]
+        crafted: bool,
+    },
     #[command(long_about = "Dynamic event loot such as a heli crash.")]
-    DeLoot { deloot: bool },
+    DeLoot {
+        #[arg(action = ArgAction::Set)]
+        deloot: bool,
+    },
 }
 
 impl FlagValues {
@@ -93,3 +169,9 @@ impl FlagValues {
         }
     }
 }
+
+/// Parse a usage or value given as `NAME` or `user:NAME`.
+#[allow(clippy::unnecessary_wraps)]
+fn parse_named(text: &str) -> Result<Named, String> {
+    Ok(Named::from_text(text))
+}
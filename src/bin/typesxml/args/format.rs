@@ -0,0 +1,27 @@
+use clap::ValueEnum;
+use typesxml::format;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Format {
+    Xml,
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl From<Format> for format::Format {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Xml => Self::Xml,
+            #[cfg(feature = "json")]
+            Format::Json => Self::Json,
+            #[cfg(feature = "yaml")]
+            Format::Yaml => Self::Yaml,
+            #[cfg(feature = "toml")]
+            Format::Toml => Self::Toml,
+        }
+    }
+}
@@ -1,29 +1,68 @@
-use serde_rw::{FromFile, ToFile};
+use crate::args::Format;
+use std::path::Path;
 use std::process::exit;
-use typesxml::Types;
+use typesxml::economycore;
+use typesxml::mission::Mission;
+use typesxml::{format, CaseSensitivity, Type, Types};
+
+/// Return whether the given path refers to a mission rather than a single XML file.
+pub fn is_mission(filename: &str) -> bool {
+    let path = Path::new(filename);
+    path.is_dir()
+        || path
+            .file_name()
+            .is_some_and(|name| name == economycore::FILE_NAME)
+}
+
+pub fn read_mission_or_exit(filename: &str) -> Mission {
+    Mission::load(filename).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        exit(1);
+    })
+}
 
 pub fn read_types_or_exit(filename: &str, strict: bool) -> Types {
-    if strict {
-        Types::from_file(filename)
+    let mut types = if strict {
+        Types::read(filename)
     } else {
         Types::read_gracefully(filename)
     }
     .unwrap_or_else(|error| {
         eprintln!("{filename}\n{error}");
         exit(1);
-    })
+    });
+    types.set_case_sensitivity(CaseSensitivity::Insensitive);
+    types
 }
 
 pub fn write_type_or_exit(types: &Types, filename: Option<&str>) {
     filename.map_or_else(
         || println!("{types}"),
         |filename| {
-            types
-                .write_to_file_pretty(filename)
-                .unwrap_or_else(|error| {
-                    eprintln!("{error}");
-                    exit(3);
-                });
+            types.write(filename).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                exit(3);
+            });
+        },
+    );
+}
+
+pub fn print_type_or_exit(typ: &Type, format: Option<Format>) {
+    format.map_or_else(
+        || println!("{typ}"),
+        |format| {
+            format::Format::from(format)
+                .serialize_type(typ)
+                .map_or_else(
+                    |error| {
+                        eprintln!(
+                            "Could not serialize {}: {error}",
+                            format::Format::from(format)
+                        );
+                        exit(3);
+                    },
+                    |text| println!("{}", text.trim_end()),
+                );
         },
     );
 }
@@ -0,0 +1,57 @@
+use clap::{Subcommand, ValueEnum};
+use typesxml::adjust;
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum Operation {
+    #[command(long_about = "Add the amount to the field.")]
+    Add {
+        #[arg(allow_negative_numbers = true)]
+        amount: i64,
+    },
+    #[command(long_about = "Subtract the amount from the field.")]
+    Subtract {
+        #[arg(allow_negative_numbers = true)]
+        amount: i64,
+    },
+    #[command(long_about = "Multiply the field by the factor and round the result.")]
+    Multiply {
+        factor: f64,
+        #[arg(long, short, value_enum, default_value_t = Rounding::Nearest)]
+        rounding: Rounding,
+    },
+    #[command(long_about = "Clamp the field to the inclusive range.")]
+    Clamp {
+        #[arg(allow_negative_numbers = true)]
+        min: i64,
+        #[arg(allow_negative_numbers = true)]
+        max: i64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Rounding {
+    Nearest,
+    Down,
+    Up,
+}
+
+impl From<&Operation> for adjust::Operation {
+    fn from(operation: &Operation) -> Self {
+        match *operation {
+            Operation::Add { amount } => Self::Add(amount),
+            Operation::Subtract { amount } => Self::Subtract(amount),
+            Operation::Multiply { factor, rounding } => Self::Multiply(factor, rounding.into()),
+            Operation::Clamp { min, max } => Self::Clamp(min, max),
+        }
+    }
+}
+
+impl From<Rounding> for adjust::Rounding {
+    fn from(rounding: Rounding) -> Self {
+        match rounding {
+            Rounding::Nearest => Self::Nearest,
+            Rounding::Down => Self::Down,
+            Rounding::Up => Self::Up,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use clap::{ArgGroup, Args};
+use regex::Regex;
+use typesxml::filter::Filter;
+use typesxml::Type;
+
+#[derive(Clone, Debug, Args)]
+#[command(group(ArgGroup::new("selector").required(true).args(["type", "regex", "filter"])))]
+pub struct Selector {
+    #[arg(index = 1, name = "type")]
+    name: Option<String>,
+    #[arg(long, short, help = "Select all types whose name matches the regex")]
+    regex: Option<Regex>,
+    #[arg(
+        long = "where",
+        short = 'w',
+        help = "Select all types matching the filter expression"
+    )]
+    filter: Option<Filter>,
+}
+
+impl Selector {
+    /// Return the name if a single type is selected by its name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn matches(&self, typ: &Type) -> bool {
+        self.name
+            .as_ref()
+            .is_none_or(|name| typ.get_name().eq_ignore_ascii_case(name))
+            && self
+                .regex
+                .as_ref()
+                .is_none_or(|regex| regex.is_match(typ.get_name()))
+            && self
+                .filter
+                .as_ref()
+                .is_none_or(|filter| filter.matches(typ))
+    }
+
+    pub fn report_no_match(&self) {
+        self.name.as_ref().map_or_else(
+            || eprintln!("No matching types"),
+            |name| eprintln!("No such type: {name}"),
+        );
+    }
+}
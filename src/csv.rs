@@ -0,0 +1,223 @@
+//! Conversion of types from and to CSV for spreadsheet balancing.
+//!
+//! Each type is one row. Flags are columns holding `0` or `1`, while tags, usages
+//! and values are lists separated by `|`. Empty cells denote unset fields of new types
+//! and leave the fields of existing types untouched.
+
+mod import;
+mod row_error;
+
+use crate::{Named, Type, Types};
+use std::io::{Read, Write};
+
+pub use import::Import;
+pub use row_error::RowError;
+
+/// Separator of names within tag, usage and value cells.
+pub const LIST_SEPARATOR: char = '|';
+
+const HEADER: [&str; 18] = [
+    "name",
+    "nominal",
+    "lifetime",
+    "restock",
+    "min",
+    "quantmin",
+    "quantmax",
+    "cost",
+    "count_in_cargo",
+    "count_in_hoarder",
+    "count_in_map",
+    "count_in_player",
+    "crafted",
+    "deloot",
+    "category",
+    "tags",
+    "usages",
+    "values",
+];
+
+/// Write the given types as CSV.
+///
+/// # Errors
+/// Returns an `anyhow::Error` if writing fails.
+pub fn to_csv<'types, W>(
+    types: impl IntoIterator<Item = &'types Type>,
+    writer: W,
+    delimiter: u8,
+) -> Result<(), anyhow::Error>
+where
+    W: Write,
+{
+    let mut writer = ::csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(writer);
+    writer.write_record(HEADER)?;
+
+    for typ in types {
+        let flags = typ.get_flags();
+        writer.write_record([
+            typ.get_name().to_string(),
+            optional(typ.get_nominal()),
+            typ.get_lifetime().to_string(),
+            optional(typ.get_restock()),
+            typ.get_min().to_string(),
+            optional(typ.get_quantmin()),
+            typ.get_quantmax().to_string(),
+            optional(typ.get_cost()),
+            u8::from(flags.get_count_in_cargo()).to_string(),
+            u8::from(flags.get_count_in_hoarder()).to_string(),
+            u8::from(flags.get_count_in_map()).to_string(),
+            u8::from(flags.get_count_in_player()).to_string(),
+            u8::from(flags.get_crafted()).to_string(),
+            u8::from(flags.get_deloot()).to_string(),
+            optional(typ.get_category()),
+            list(typ.get_tags()),
+            list(typ.get_usages()),
+            list(typ.get_values()),
+        ])?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read types from CSV.
+///
+/// Columns are identified by the header row and may appear in any order.
+/// Rows naming a type that exists in `types` only update the fields whose cells are present
+/// and not empty. Rows creating a new type must provide lifetime, min and quantmax.
+/// Rows that fail to parse are not imported but reported.
+///
+/// # Arguments
+/// * `reader` - The CSV to read.
+/// * `delimiter` - The field delimiter.
+/// * `types` - The types the rows are to be imported into.
+///
+/// # Errors
+/// Returns an `anyhow::Error` if the CSV cannot be read or the header lacks the name column.
+pub fn from_csv<R>(reader: R, delimiter: u8, types: &Types) -> Result<Import, anyhow::Error>
+where
+    R: Read,
+{
+    let mut reader = ::csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(reader);
+    let header = reader.headers()?.clone();
+    let columns: Vec<Option<usize>> = HEADER
+        .iter()
+        .map(|column| {
+            header
+                .iter()
+                .position(|name| name.trim().eq_ignore_ascii_case(column))
+        })
+        .collect();
+
+    if columns[0].is_none() {
+        return Err(anyhow::Error::msg("missing column: name"));
+    }
+
+    let mut import = Import::default();
+
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) if error.is_io_error() => return Err(error.into()),
+            Err(error) => {
+                import.fail(RowError::new(
+                    error.position().map_or(0, ::csv::Position::line),
+                    None,
+                    error.to_string(),
+                ));
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, ::csv::Position::line);
+        let cell = |index: usize| {
+            columns[index]
+                .and_then(|column| record.get(column))
+                .map(str::trim)
+                .filter(|cell| !cell.is_empty())
+        };
+
+        match import::parse_row(cell, |name| types.get(name)) {
+            Ok(typ) => import.push(typ),
+            Err(error) => import.fail(RowError::new(
+                line,
+                cell(0).map(ToString::to_string),
+                error.to_string(),
+            )),
+        }
+    }
+
+    Ok(import)
+}
+
+fn optional<T>(value: Option<T>) -> String
+where
+    T: ToString,
+{
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn list(names: Option<&[Named]>) -> String {
+    names
+        .unwrap_or_default()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(&LIST_SEPARATOR.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_csv, to_csv, Import};
+    use crate::{Named, Type, Types};
+
+    fn existing() -> Types {
+        let mut typ = Type::new("Apple");
+        typ.set_nominal(Some(20));
+        typ.set_lifetime(3600);
+        typ.set_min(5);
+        typ.set_quantmax(-1);
+        typ.set_usages(Some(&[Named::new("Town".to_string())]));
+        let mut types = Types::default();
+        types.insert(typ);
+        types
+    }
+
+    fn import(csv: &str, types: &Types) -> Import {
+        from_csv(csv.as_bytes(), b',', types).unwrap()
+    }
+
+    #[test]
+    fn reads_what_it_writes() {
+        let types = existing();
+        let mut csv = Vec::new();
+        to_csv(types.types(), &mut csv, b',').unwrap();
+        let import = import(std::str::from_utf8(&csv).unwrap(), &Types::default());
+        assert!(import.errors().is_empty());
+        assert_eq!(import.types(), types.types().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn requires_lifetime_min_and_quantmax_of_new_types() {
+        let import = import("name,nominal\nBanana,10\n", &existing());
+        assert!(import.types().is_empty());
+        assert_eq!(import.errors()[0].message(), "missing lifetime");
+    }
+
+    #[test]
+    fn updates_only_the_present_cells_of_existing_types() {
+        let mut types = existing();
+        import("name,nominal,lifetime,usages\nApple,7,\n", &types).apply(&mut types);
+        let apple = types.get("Apple").unwrap();
+        assert_eq!(apple.get_nominal(), Some(7));
+        assert_eq!(apple.get_lifetime(), 3600);
+        assert_eq!(
+            apple.get_usages(),
+            Some([Named::new("Town".to_string())].as_slice())
+        );
+    }
+}
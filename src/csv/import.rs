@@ -0,0 +1,196 @@
+use crate::csv::{RowError, LIST_SEPARATOR};
+use crate::{Flags, Named, Type, Types};
+use std::str::FromStr;
+
+/// The types read from CSV along with the rows that failed to parse
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Import {
+    types: Vec<Type>,
+    errors: Vec<RowError>,
+}
+
+impl Import {
+    #[must_use]
+    pub fn types(&self) -> &[Type] {
+        &self.types
+    }
+
+    #[must_use]
+    pub fn errors(&self) -> &[RowError] {
+        &self.errors
+    }
+
+    #[must_use]
+    pub fn into_types(self) -> Vec<Type> {
+        self.types
+    }
+
+    /// Insert the imported types into the given types, replacing existing types of the same name.
+    pub fn apply(self, types: &mut Types) {
+        for typ in self.types {
+            types.insert(typ);
+        }
+    }
+
+    pub(crate) fn push(&mut self, typ: Type) {
+        self.types.push(typ);
+    }
+
+    pub(crate) fn fail(&mut self, error: RowError) {
+        self.errors.push(error);
+    }
+}
+
+/// Parse a type from the cells of a row, indexed like the header.
+///
+/// If `existing` knows the named type, only the fields of present cells are updated on a copy of it.
+/// Otherwise, a new type is created, which requires lifetime, min and quantmax.
+pub fn parse_row<'row, 'types>(
+    cell: impl Fn(usize) -> Option<&'row str>,
+    existing: impl Fn(&str) -> Option<&'types Type>,
+) -> Result<Type, anyhow::Error> {
+    let name = cell(0).ok_or_else(|| anyhow::Error::msg("missing name"))?;
+
+    if let Some(existing) = existing(name) {
+        return update(existing.clone(), cell);
+    }
+
+    let mut typ = Type::new(name);
+    typ.set_nominal(optional(cell(1), "nominal")?);
+    typ.set_lifetime(mandatory(cell(2), "lifetime")?);
+    typ.set_restock(optional(cell(3), "restock")?);
+    typ.set_min(mandatory(cell(4), "min")?);
+    typ.set_quantmin(optional(cell(5), "quantmin")?);
+    typ.set_quantmax(mandatory(cell(6), "quantmax")?);
+    typ.set_cost(optional(cell(7), "cost")?);
+    let mut flags = Flags::default();
+    flags.set_count_in_cargo(flag(cell(8), "count_in_cargo")?.unwrap_or_default());
+    flags.set_count_in_hoarder(flag(cell(9), "count_in_hoarder")?.unwrap_or_default());
+    flags.set_count_in_map(flag(cell(10), "count_in_map")?.unwrap_or_default());
+    flags.set_count_in_player(flag(cell(11), "count_in_player")?.unwrap_or_default());
+    flags.set_crafted(flag(cell(12), "crafted")?.unwrap_or_default());
+    flags.set_deloot(flag(cell(13), "deloot")?.unwrap_or_default());
+    typ.set_flags(flags);
+    typ.set_category(cell(14).map(Named::from_text).as_ref());
+    typ.set_tags(list(cell(15)).as_deref());
+    typ.set_usages(list(cell(16)).as_deref());
+    typ.set_values(list(cell(17)).as_deref());
+    Ok(typ)
+}
+
+/// Take the values of the present cells and keep the other fields of the type.
+fn update<'row>(
+    mut typ: Type,
+    cell: impl Fn(usize) -> Option<&'row str>,
+) -> Result<Type, anyhow::Error> {
+    if let Some(nominal) = optional(cell(1), "nominal")? {
+        typ.set_nominal(Some(nominal));
+    }
+
+    if let Some(lifetime) = optional(cell(2), "lifetime")? {
+        typ.set_lifetime(lifetime);
+    }
+
+    if let Some(restock) = optional(cell(3), "restock")? {
+        typ.set_restock(Some(restock));
+    }
+
+    if let Some(min) = optional(cell(4), "min")? {
+        typ.set_min(min);
+    }
+
+    if let Some(quantmin) = optional(cell(5), "quantmin")? {
+        typ.set_quantmin(Some(quantmin));
+    }
+
+    if let Some(quantmax) = optional(cell(6), "quantmax")? {
+        typ.set_quantmax(quantmax);
+    }
+
+    if let Some(cost) = optional(cell(7), "cost")? {
+        typ.set_cost(Some(cost));
+    }
+
+    let mut flags = typ.get_flags().clone();
+
+    if let Some(count_in_cargo) = flag(cell(8), "count_in_cargo")? {
+        flags.set_count_in_cargo(count_in_cargo);
+    }
+
+    if let Some(count_in_hoarder) = flag(cell(9), "count_in_hoarder")? {
+        flags.set_count_in_hoarder(count_in_hoarder);
+    }
+
+    if let Some(count_in_map) = flag(cell(10), "count_in_map")? {
+        flags.set_count_in_map(count_in_map);
+    }
+
+    if let Some(count_in_player) = flag(cell(11), "count_in_player")? {
+        flags.set_count_in_player(count_in_player);
+    }
+
+    if let Some(crafted) = flag(cell(12), "crafted")? {
+        flags.set_crafted(crafted);
+    }
+
+    if let Some(deloot) = flag(cell(13), "deloot")? {
+        flags.set_deloot(deloot);
+    }
+
+    typ.set_flags(flags);
+
+    if let Some(category) = cell(14).map(Named::from_text) {
+        typ.set_category(Some(&category));
+    }
+
+    if let Some(tags) = list(cell(15)) {
+        typ.set_tags(Some(&tags));
+    }
+
+    if let Some(usages) = list(cell(16)) {
+        typ.set_usages(Some(&usages));
+    }
+
+    if let Some(values) = list(cell(17)) {
+        typ.set_values(Some(&values));
+    }
+
+    Ok(typ)
+}
+
+fn optional<T>(cell: Option<&str>, column: &str) -> Result<Option<T>, anyhow::Error>
+where
+    T: FromStr,
+{
+    cell.map(|text| {
+        text.parse()
+            .map_err(|_| anyhow::Error::msg(format!("invalid {column}: {text}")))
+    })
+    .transpose()
+}
+
+fn mandatory<T>(cell: Option<&str>, column: &str) -> Result<T, anyhow::Error>
+where
+    T: FromStr,
+{
+    optional(cell, column)?.ok_or_else(|| anyhow::Error::msg(format!("missing {column}")))
+}
+
+fn flag(cell: Option<&str>, column: &str) -> Result<Option<bool>, anyhow::Error> {
+    match cell.map(str::to_ascii_lowercase).as_deref() {
+        None => Ok(None),
+        Some("0" | "false") => Ok(Some(false)),
+        Some("1" | "true") => Ok(Some(true)),
+        Some(text) => Err(anyhow::Error::msg(format!("invalid {column}: {text}"))),
+    }
+}
+
+fn list(cell: Option<&str>) -> Option<Vec<Named>> {
+    cell.map(|text| {
+        text.split(LIST_SEPARATOR)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(Named::from_text)
+            .collect()
+    })
+}
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+/// A CSV row that could not be imported
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RowError {
+    line: u64,
+    name: Option<String>,
+    message: String,
+}
+
+impl RowError {
+    #[must_use]
+    pub const fn new(line: u64, name: Option<String>, message: String) -> Self {
+        Self {
+            line,
+            name,
+            message,
+        }
+    }
+
+    #[must_use]
+    pub const fn line(&self) -> u64 {
+        self.line
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl Display for RowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}", self.line)?;
+
+        if let Some(ref name) = self.name {
+            write!(f, " ({name})")?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
@@ -0,0 +1,7 @@
+//! Detection and resolution of types defined more than once.
+
+mod duplicate;
+mod policy;
+
+pub use duplicate::Duplicate;
+pub use policy::Policy;
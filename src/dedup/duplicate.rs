@@ -0,0 +1,118 @@
+use crate::dedup::Policy;
+use crate::diff::TypeDiff;
+use crate::Type;
+use std::fmt::{Display, Formatter};
+
+/// A type defined more than once
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Duplicate {
+    positions: Vec<usize>,
+    copies: Vec<Type>,
+    fields: Option<Vec<Vec<String>>>,
+}
+
+impl Duplicate {
+    pub(crate) fn new(
+        positions: Vec<usize>,
+        copies: Vec<Type>,
+        fields: Option<Vec<Vec<String>>>,
+    ) -> Self {
+        Self {
+            positions,
+            copies,
+            fields,
+        }
+    }
+
+    /// Return the name of the first copy.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.copies.first().map_or("", Type::get_name)
+    }
+
+    /// Return the positions of the copies within the types.
+    #[must_use]
+    pub fn positions(&self) -> &[usize] {
+        &self.positions
+    }
+
+    #[must_use]
+    pub fn copies(&self) -> &[Type] {
+        &self.copies
+    }
+
+    /// Return whether all copies have exactly the same name.
+    #[must_use]
+    pub fn is_exact(&self) -> bool {
+        self.copies
+            .windows(2)
+            .all(|pair| pair[0].get_name() == pair[1].get_name())
+    }
+
+    /// Return how each later copy differs from the first one.
+    ///
+    /// Copies equal to the first one are omitted.
+    #[must_use]
+    pub fn differences(&self) -> Vec<TypeDiff> {
+        self.copies
+            .split_first()
+            .map(|(first, rest)| {
+                rest.iter()
+                    .filter_map(|copy| TypeDiff::new(first, copy))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Return the position at which the resolved type is kept.
+    #[must_use]
+    pub fn kept_position(&self, policy: Policy) -> usize {
+        match policy {
+            Policy::KeepFirst | Policy::MergeFields => self.positions.first(),
+            Policy::KeepLast => self.positions.last(),
+        }
+        .copied()
+        .unwrap_or_default()
+    }
+
+    /// Resolve the copies into a single type.
+    ///
+    /// With [`Policy::MergeFields`], later copies only override the fields present in
+    /// their elements if the types were read from a document. Otherwise their mandatory
+    /// fields always override those of earlier copies.
+    #[must_use]
+    pub fn resolve(&self, policy: Policy) -> Option<Type> {
+        match policy {
+            Policy::KeepFirst => self.copies.first().cloned(),
+            Policy::KeepLast => self.copies.last().cloned(),
+            Policy::MergeFields => self.copies.split_first().map(|(first, rest)| {
+                rest.iter()
+                    .enumerate()
+                    .fold(first.clone(), |typ, (index, copy)| {
+                        typ.overlay(
+                            copy,
+                            self.fields
+                                .as_ref()
+                                .map(|fields| fields[index + 1].as_slice()),
+                        )
+                    })
+            }),
+        }
+    }
+}
+
+impl Display for Duplicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is defined {} times", self.name(), self.copies.len())?;
+
+        if !self.is_exact() {
+            write!(f, " with differently cased names")?;
+        }
+
+        for difference in self.differences() {
+            write!(f, "\n{difference}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+/// How to resolve the copies of a duplicate type
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Policy {
+    /// Keep the first copy.
+    KeepFirst,
+    /// Keep the last copy, which is the one the game uses.
+    #[default]
+    KeepLast,
+    /// Override the fields of the first copy with those present in the later ones.
+    MergeFields,
+}
@@ -0,0 +1,147 @@
+//! Structured differences between two types collections.
+
+mod change;
+mod type_diff;
+mod value;
+
+use crate::document::{Element, Node};
+use crate::Type;
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+
+pub use change::Change;
+pub use type_diff::TypeDiff;
+pub use value::Value;
+
+/// The differences between two types collections
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct TypesDiff {
+    #[serde(serialize_with = "names")]
+    added: Vec<Type>,
+    #[serde(serialize_with = "names")]
+    removed: Vec<Type>,
+    modified: Vec<TypeDiff>,
+}
+
+impl TypesDiff {
+    #[must_use]
+    pub const fn new(added: Vec<Type>, removed: Vec<Type>, modified: Vec<TypeDiff>) -> Self {
+        Self {
+            added,
+            removed,
+            modified,
+        }
+    }
+
+    #[must_use]
+    pub fn added(&self) -> &[Type] {
+        &self.added
+    }
+
+    #[must_use]
+    pub fn removed(&self) -> &[Type] {
+        &self.removed
+    }
+
+    #[must_use]
+    pub fn modified(&self) -> &[TypeDiff] {
+        &self.modified
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+
+    /// Render the differences as an RFC 5261 XML patch.
+    ///
+    /// Modified types are replaced as a whole.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if a type cannot be serialized.
+    pub fn to_xml_patch(&self) -> Result<String, anyhow::Error> {
+        let mut diff = Element::new("diff");
+        let mut push = |element: Element| {
+            diff.children_mut().push(Node::Text("\n    ".to_string()));
+            diff.children_mut().push(Node::Element(element));
+        };
+
+        for typ in &self.removed {
+            let mut remove = Element::new("remove");
+            remove.set_attribute("sel", &selector(typ.get_name()));
+            push(remove);
+        }
+
+        for diff in &self.modified {
+            let mut replace = Element::new("replace");
+            replace.set_attribute("sel", &selector(diff.name()));
+            replace.children_mut().extend([
+                Node::Text("\n        ".to_string()),
+                Node::Element(diff.after().to_element("\n        ", "\n            ")?),
+                Node::Text("\n    ".to_string()),
+            ]);
+            push(replace);
+        }
+
+        for typ in &self.added {
+            let mut add = Element::new("add");
+            add.set_attribute("sel", "/types");
+            add.children_mut().extend([
+                Node::Text("\n        ".to_string()),
+                Node::Element(typ.to_element("\n        ", "\n            ")?),
+                Node::Text("\n    ".to_string()),
+            ]);
+            push(add);
+        }
+
+        diff.children_mut().push(Node::Text("\n".to_string()));
+        Ok(diff.to_string())
+    }
+}
+
+impl Display for TypesDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for typ in &self.removed {
+            writeln!(f, "- {}", typ.get_name())?;
+        }
+
+        for typ in &self.added {
+            writeln!(f, "+ {}", typ.get_name())?;
+        }
+
+        for diff in &self.modified {
+            writeln!(f, "{diff}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn selector(name: &str) -> String {
+    format!("/types/type[@name={}]", literal(name))
+}
+
+/// Quote the given text as an XPath string literal.
+///
+/// XPath has no escape sequences, so texts containing both quote characters are
+/// split at the apostrophes and joined with `concat()`.
+fn literal(text: &str) -> String {
+    if !text.contains('\'') {
+        format!("'{text}'")
+    } else if !text.contains('"') {
+        format!("\"{text}\"")
+    } else {
+        format!(
+            "concat('{}')",
+            text.split('\'').collect::<Vec<_>>().join("', \"'\", '")
+        )
+    }
+}
+
+#[allow(clippy::ptr_arg)]
+fn names<S>(types: &Vec<Type>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(types.iter().map(Type::get_name))
+}
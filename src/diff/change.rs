@@ -0,0 +1,182 @@
+use crate::diff::Value;
+use crate::Named;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// A change to a single field of a type
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Change {
+    /// A scalar field was set, altered or unset.
+    Field {
+        field: String,
+        old: Option<Value>,
+        new: Option<Value>,
+    },
+    /// Names were added to or removed from a list field.
+    List {
+        field: String,
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    /// The names of a list field were reordered without adding or removing any.
+    Order {
+        field: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+}
+
+impl Change {
+    /// Return a field change if `old` and `new` differ.
+    pub fn field<T>(field: &str, old: Option<T>, new: Option<T>) -> Option<Self>
+    where
+        T: Into<Value> + PartialEq,
+    {
+        if old == new {
+            None
+        } else {
+            Some(Self::Field {
+                field: field.to_string(),
+                old: old.map(Into::into),
+                new: new.map(Into::into),
+            })
+        }
+    }
+
+    /// Return a list change if `old` and `new` differ.
+    ///
+    /// Names are compared as sequences, so a name listed more often than before counts as added
+    /// and one listed less often as removed. Lists holding the same names in a different order
+    /// are reported as reordered.
+    #[must_use]
+    pub fn list(field: &str, old: Option<&[Named]>, new: Option<&[Named]>) -> Option<Self> {
+        let old = old.unwrap_or_default();
+        let new = new.unwrap_or_default();
+
+        if old == new {
+            return None;
+        }
+
+        let mut remaining: Vec<&Named> = old.iter().collect();
+        let mut added = Vec::new();
+
+        for name in new {
+            if let Some(position) = remaining.iter().position(|&old| old == name) {
+                remaining.remove(position);
+            } else {
+                added.push(name.to_string());
+            }
+        }
+
+        if added.is_empty() && remaining.is_empty() {
+            Some(Self::Order {
+                field: field.to_string(),
+                old: old.iter().map(ToString::to_string).collect(),
+                new: new.iter().map(ToString::to_string).collect(),
+            })
+        } else {
+            Some(Self::List {
+                field: field.to_string(),
+                added,
+                removed: remaining.into_iter().map(ToString::to_string).collect(),
+            })
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Field { field, .. } | Self::List { field, .. } | Self::Order { field, .. } => {
+                field.as_str()
+            }
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field { field, old, new } => {
+                write!(f, "{field}: ")?;
+                fmt_option(f, old.as_ref())?;
+                write!(f, " -> ")?;
+                fmt_option(f, new.as_ref())
+            }
+            Self::List {
+                field,
+                added,
+                removed,
+            } => {
+                write!(f, "{field}:")?;
+
+                for name in added {
+                    write!(f, " +{name}")?;
+                }
+
+                for name in removed {
+                    write!(f, " -{name}")?;
+                }
+
+                Ok(())
+            }
+            Self::Order { field, old, new } => {
+                write!(f, "{field}: {} -> {}", old.join(", "), new.join(", "))
+            }
+        }
+    }
+}
+
+fn fmt_option(f: &mut Formatter<'_>, value: Option<&Value>) -> std::fmt::Result {
+    match value {
+        Some(value) => write!(f, "{value}"),
+        None => write!(f, "(unset)"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Change;
+    use crate::Named;
+
+    fn names(names: &[&str]) -> Vec<Named> {
+        names.iter().copied().map(Named::from_text).collect()
+    }
+
+    #[test]
+    fn reports_names_listed_more_or_less_often() {
+        assert_eq!(
+            Change::list(
+                "usage",
+                Some(&names(&["Town", "Town", "Farm"])),
+                Some(&names(&["Town", "Farm", "Farm"]))
+            ),
+            Some(Change::List {
+                field: "usage".to_string(),
+                added: vec!["Farm".to_string()],
+                removed: vec!["Town".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn reports_reordered_names() {
+        assert_eq!(
+            Change::list(
+                "usage",
+                Some(&names(&["Town", "Farm"])),
+                Some(&names(&["Farm", "Town"]))
+            )
+            .map(|change| change.to_string()),
+            Some("usage: Town, Farm -> Farm, Town".to_string())
+        );
+    }
+
+    #[test]
+    fn ignores_equal_lists() {
+        assert_eq!(
+            Change::list("usage", Some(&names(&["Town"])), Some(&names(&["Town"]))),
+            None
+        );
+    }
+}
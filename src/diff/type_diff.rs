@@ -0,0 +1,68 @@
+use crate::diff::Change;
+use crate::Type;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// The changes made to a single type
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct TypeDiff {
+    name: String,
+    changes: Vec<Change>,
+    #[serde(skip)]
+    before: Type,
+    #[serde(skip)]
+    after: Type,
+}
+
+impl TypeDiff {
+    /// Compare two versions of a type.
+    ///
+    /// Returns `None` if they are equal.
+    #[must_use]
+    pub fn new(old: &Type, new: &Type) -> Option<Self> {
+        let changes = old.diff(new);
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(Self {
+                name: new.get_name().to_string(),
+                changes,
+                before: old.clone(),
+                after: new.clone(),
+            })
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    #[must_use]
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    #[must_use]
+    pub const fn before(&self) -> &Type {
+        &self.before
+    }
+
+    #[must_use]
+    pub const fn after(&self) -> &Type {
+        &self.after
+    }
+}
+
+impl Display for TypeDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "~ {}", self.name)?;
+
+        for change in &self.changes {
+            write!(f, "\n    {change}")?;
+        }
+
+        Ok(())
+    }
+}
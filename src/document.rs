@@ -0,0 +1,132 @@
+mod element;
+mod node;
+mod position;
+mod source;
+mod span;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub use element::Element;
+pub use node::Node;
+pub use position::Position;
+pub use span::Span;
+
+pub(crate) use source::{arrange, assign, Entry, Source};
+
+/// A lossless XML document
+///
+/// Keeps comments, processing instructions, unknown elements and attributes
+/// as well as the original whitespace, so that writing back an unchanged
+/// document yields the original text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Document {
+    nodes: Vec<Node>,
+}
+
+impl Document {
+    /// Return the document's root element, if any.
+    #[must_use]
+    pub fn root(&self) -> Option<&Element> {
+        self.nodes.iter().find_map(Node::as_element)
+    }
+
+    /// Return the document's root element mutably, if any.
+    pub fn root_mut(&mut self) -> Option<&mut Element> {
+        self.nodes.iter_mut().find_map(Node::as_element_mut)
+    }
+
+    /// Return the document's top-level nodes.
+    #[must_use]
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Read and parse the XML document from the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file cannot be read or is not well-formed XML.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        std::fs::read_to_string(filename)?.parse()
+    }
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for node in &self.nodes {
+            write!(f, "{node}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Document {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut reader = Reader::from_str(text);
+        reader.check_end_names(true);
+        let mut stack: Vec<Element> = Vec::new();
+        let mut nodes = Vec::new();
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        loop {
+            let start = Position::locate(text, &line_starts, reader.buffer_position());
+            let event = reader.read_event().map_err(|error| {
+                anyhow::Error::msg(format!("{error} at byte {}", reader.buffer_position()))
+            })?;
+            let span = Span::new(
+                start,
+                Position::locate(text, &line_starts, reader.buffer_position()),
+            );
+            let node = match event {
+                Event::Start(start) => {
+                    stack.push(Element::from_start(&start, false, Some(span))?);
+                    continue;
+                }
+                Event::End(_) => {
+                    let mut element = stack
+                        .pop()
+                        .ok_or_else(|| anyhow::Error::msg("unexpected closing tag"))?;
+                    element.close(span.end());
+                    Node::Element(element)
+                }
+                Event::Empty(start) => {
+                    Node::Element(Element::from_start(&start, true, Some(span))?)
+                }
+                Event::Text(text) => Node::Text(String::from_utf8(text.into_inner().into())?),
+                Event::CData(text) => Node::CData(String::from_utf8(text.into_inner().into())?),
+                Event::Comment(text) => Node::Comment(String::from_utf8(text.into_inner().into())?),
+                Event::Decl(decl) => Node::Declaration(String::from_utf8(decl.to_vec())?),
+                Event::PI(text) => {
+                    Node::ProcessingInstruction(String::from_utf8(text.into_inner().into())?)
+                }
+                Event::DocType(text) => Node::DocType(String::from_utf8(text.into_inner().into())?),
+                Event::Eof => break,
+            };
+
+            if let Some(parent) = stack.last_mut() {
+                parent.children_mut().push(node);
+            } else {
+                nodes.push(node);
+            }
+        }
+
+        if let Some(element) = stack.last() {
+            return Err(anyhow::Error::msg(format!(
+                "unclosed element: <{}>",
+                element.name()
+            )));
+        }
+
+        Ok(Self { nodes })
+    }
+}
@@ -0,0 +1,284 @@
+use crate::document::{Document, Node, Position, Span};
+use quick_xml::escape::{escape, partial_escape, unescape};
+use quick_xml::events::BytesStart;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An XML element
+///
+/// The original start tag is kept until the attributes are modified,
+/// after which only the whitespace before its end is kept.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    start: Option<String>,
+    children: Vec<Node>,
+    empty: bool,
+    span: Option<Span>,
+}
+
+impl Element {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            attributes: Vec::new(),
+            start: None,
+            children: Vec::new(),
+            empty: true,
+            span: None,
+        }
+    }
+
+    pub(crate) fn from_start(
+        start: &BytesStart,
+        empty: bool,
+        span: Option<Span>,
+    ) -> Result<Self, anyhow::Error> {
+        let mut attributes = Vec::new();
+
+        for attribute in start.attributes() {
+            let attribute = attribute?;
+            attributes.push((
+                String::from_utf8(attribute.key.as_ref().to_vec())?,
+                String::from_utf8(attribute.value.to_vec())?,
+            ));
+        }
+
+        Ok(Self {
+            name: String::from_utf8(start.name().as_ref().to_vec())?,
+            attributes,
+            start: Some(String::from_utf8(start.to_vec())?),
+            children: Vec::new(),
+            empty,
+            span,
+        })
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Return the range the element occupies in the document it was parsed from.
+    #[must_use]
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Return the position of the element's start tag in the document it was parsed from.
+    #[must_use]
+    pub fn position(&self) -> Option<Position> {
+        self.span.map(|span| span.start())
+    }
+
+    /// Extend the element's span to the end of its closing tag.
+    pub(crate) const fn close(&mut self, end: Position) {
+        if let Some(span) = self.span {
+            self.span = Some(Span::new(span.start(), end));
+        }
+    }
+
+    /// Forget where the element and its descendants were parsed from.
+    pub(crate) fn clear_spans(&mut self) {
+        self.span = None;
+        self.elements_mut().for_each(Self::clear_spans);
+    }
+
+    /// Return the unescaped value of the given attribute.
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| unescape(value).map_or_else(|_| value.clone(), Into::into))
+    }
+
+    /// Return the names of all attributes in document order.
+    pub fn attribute_names(&self) -> impl Iterator<Item = &str> {
+        self.attributes.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Set the given attribute, appending it if it does not exist yet.
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        let value = partial_escape(value).replace('"', "&quot;");
+
+        if let Some((_, existing)) = self.attributes.iter_mut().find(|(key, _)| key == name) {
+            if *existing == value {
+                return;
+            }
+
+            *existing = value;
+        } else {
+            self.attributes.push((name.to_string(), value));
+        }
+
+        self.rebuild_start();
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) {
+        let length = self.attributes.len();
+        self.attributes.retain(|(key, _)| key != name);
+
+        if self.attributes.len() != length {
+            self.rebuild_start();
+        }
+    }
+
+    /// Return the whitespace before the end of the original start tag.
+    #[must_use]
+    pub fn padding(&self) -> &str {
+        self.start
+            .as_deref()
+            .map_or("", |start| &start[start.trim_end().len()..])
+    }
+
+    /// Set the whitespace before the end of the start tag.
+    pub fn set_padding(&mut self, padding: &str) {
+        self.start = Some(format!("{}{padding}", self.name));
+        self.rebuild_start();
+    }
+
+    #[must_use]
+    pub fn children(&self) -> &[Node] {
+        &self.children
+    }
+
+    pub const fn children_mut(&mut self) -> &mut Vec<Node> {
+        self.empty = false;
+        &mut self.children
+    }
+
+    /// Iterate over all child elements.
+    pub fn elements(&self) -> impl Iterator<Item = &Self> {
+        self.children.iter().filter_map(Node::as_element)
+    }
+
+    /// Iterate over all child elements mutably.
+    pub fn elements_mut(&mut self) -> impl Iterator<Item = &mut Self> {
+        self.children.iter_mut().filter_map(Node::as_element_mut)
+    }
+
+    /// Return the first child element with the given name.
+    #[must_use]
+    pub fn element(&self, name: &str) -> Option<&Self> {
+        self.elements().find(|element| element.name == name)
+    }
+
+    /// Return the first child element with the given name mutably.
+    pub fn element_mut(&mut self, name: &str) -> Option<&mut Self> {
+        self.elements_mut().find(|element| element.name == name)
+    }
+
+    /// Return the unescaped text content of this element.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|node| match node {
+                Node::Text(text) => Some(unescape(text).map_or_else(|_| text.clone(), Into::into)),
+                Node::CData(text) => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Replace the element's content with the given text.
+    pub fn set_text(&mut self, text: &str) {
+        self.children = vec![Node::Text(escape(text).into_owned())];
+        self.empty = false;
+    }
+
+    /// Return the whitespace preceding the first child element, if any.
+    #[must_use]
+    pub fn child_indent(&self) -> Option<&str> {
+        self.children
+            .windows(2)
+            .find(|pair| pair[1].as_element().is_some())
+            .and_then(|pair| pair[0].as_whitespace())
+    }
+
+    /// Remove all child elements with the given name along with their preceding whitespace.
+    ///
+    /// Returns the index at which the first removed element was located.
+    pub fn remove_elements(&mut self, name: &str) -> Option<usize> {
+        let mut first = None;
+        let mut index = 0;
+
+        while index < self.children.len() {
+            if self.children[index].is_element_named(name) {
+                self.children.remove(index);
+
+                if index > 0 && self.children[index - 1].as_whitespace().is_some() {
+                    index -= 1;
+                    self.children.remove(index);
+                }
+
+                first.get_or_insert(index);
+            } else {
+                index += 1;
+            }
+        }
+
+        first
+    }
+
+    /// Rewrite the original start tag from the attributes, keeping the whitespace before its end.
+    fn rebuild_start(&mut self) {
+        if self.start.is_some() {
+            let padding = self.padding();
+            let mut rebuilt = self.name.clone();
+
+            for (key, value) in &self.attributes {
+                rebuilt.push_str(&format!(" {key}=\"{value}\""));
+            }
+
+            rebuilt.push_str(padding);
+            self.start = Some(rebuilt);
+        }
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<")?;
+
+        if let Some(ref start) = self.start {
+            write!(f, "{start}")?;
+        } else {
+            write!(f, "{}", self.name)?;
+
+            for (key, value) in &self.attributes {
+                write!(f, " {key}=\"{value}\"")?;
+            }
+        }
+
+        if self.empty && self.children.is_empty() {
+            return write!(f, "/>");
+        }
+
+        write!(f, ">")?;
+
+        for child in &self.children {
+            write!(f, "{child}")?;
+        }
+
+        write!(f, "</{}>", self.name)
+    }
+}
+
+impl FromStr for Element {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.parse::<Document>()?
+            .nodes
+            .into_iter()
+            .find_map(|node| match node {
+                Node::Element(element) => Some(element),
+                _ => None,
+            })
+            .ok_or_else(|| anyhow::Error::msg("no element found"))
+    }
+}
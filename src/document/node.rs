@@ -0,0 +1,61 @@
+use crate::document::Element;
+use std::fmt::{Display, Formatter};
+
+/// A node within an XML document
+///
+/// Textual nodes hold their content verbatim, i.e. still escaped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+    CData(String),
+    Comment(String),
+    Declaration(String),
+    ProcessingInstruction(String),
+    DocType(String),
+}
+
+impl Node {
+    #[must_use]
+    pub const fn as_element(&self) -> Option<&Element> {
+        match self {
+            Self::Element(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    pub const fn as_element_mut(&mut self) -> Option<&mut Element> {
+        match self {
+            Self::Element(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    /// Return the whitespace if this node is a whitespace-only text node.
+    #[must_use]
+    pub fn as_whitespace(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) if text.chars().all(char::is_whitespace) => Some(text.as_str()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_element_named(&self, name: &str) -> bool {
+        self.as_element()
+            .is_some_and(|element| element.name() == name)
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Element(element) => write!(f, "{element}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::CData(text) => write!(f, "<![CDATA[{text}]]>"),
+            Self::Comment(text) => write!(f, "<!--{text}-->"),
+            Self::Declaration(text) | Self::ProcessingInstruction(text) => write!(f, "<?{text}?>"),
+            Self::DocType(text) => write!(f, "<!DOCTYPE {text}>"),
+        }
+    }
+}
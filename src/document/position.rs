@@ -0,0 +1,46 @@
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// A one-based line and column within a document
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    #[must_use]
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Locate the given byte offset within the text.
+    ///
+    /// `line_starts` holds the byte offsets at which the text's lines start.
+    pub(crate) fn locate(text: &str, line_starts: &[usize], offset: usize) -> Self {
+        let line = line_starts.partition_point(|&start| start <= offset);
+        let start = line_starts[line.saturating_sub(1)];
+        Self {
+            line,
+            column: text
+                .get(start..offset)
+                .map_or(1, |prefix| prefix.chars().count() + 1),
+        }
+    }
+
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
@@ -0,0 +1,604 @@
+use crate::document::{Document, Element, Node};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_rw::ToXml;
+use std::collections::{HashMap, HashSet};
+
+const DEFAULT_INDENT: &str = "\n    ";
+
+/// An item read from a child element of a document's root, e.g. an `<event>` of `events.xml`
+pub trait Entry: Clone + DeserializeOwned + PartialEq + Serialize {
+    /// The name of the document's root element.
+    const ROOT: &'static str;
+    /// The name of the elements the items are read from.
+    const ELEMENT: &'static str;
+
+    /// Return the name the item is identified by.
+    fn key(&self) -> &str;
+
+    /// Set the name the item is identified by.
+    fn set_key(&mut self, key: &str);
+}
+
+/// The document a collection of items was read from
+///
+/// Keeps the original items alongside the document, so that changes can be
+/// written back onto the respective elements only. Items are matched to their
+/// elements by name case-insensitively, like the game does.
+#[derive(Clone, Debug)]
+pub struct Source<T> {
+    document: Document,
+    items: Vec<T>,
+}
+
+impl<T> Source<T>
+where
+    T: Entry,
+{
+    /// Parse the items from the given document strictly.
+    pub fn parse(document: Document) -> Result<(Self, Vec<T>), anyhow::Error> {
+        let items: Vec<T> = root::<T>(&document)?
+            .elements()
+            .filter(|element| element.name() == T::ELEMENT)
+            .map(|element| parse(element).map_err(|error| located::<T>(element, &error)))
+            .collect::<Result<_, _>>()?;
+        Ok((
+            Self {
+                document,
+                items: items.clone(),
+            },
+            items,
+        ))
+    }
+
+    /// Parse the items from the given document gracefully.
+    ///
+    /// Elements that cannot be recovered are removed and those that could
+    /// not be read as is are rewritten from the recovered item.
+    pub fn recover(
+        mut document: Document,
+        recover: fn(&Element) -> Result<Option<T>, anyhow::Error>,
+    ) -> Result<(Self, Vec<T>), anyhow::Error> {
+        let root = root_mut::<T>(&mut document)?;
+        let indent = root.child_indent().unwrap_or(DEFAULT_INDENT).to_string();
+        let mut items = Vec::new();
+        let mut index = 0;
+
+        while index < root.children().len() {
+            let Some(element) = root.children()[index]
+                .as_element()
+                .filter(|element| element.name() == T::ELEMENT)
+            else {
+                index += 1;
+                continue;
+            };
+
+            match recover(element).map_err(|error| located::<T>(element, &error))? {
+                Some(item) => {
+                    if parse::<T>(element).ok().as_ref() != Some(&item) {
+                        root.children_mut()[index] =
+                            Node::Element(indented(to_element(&item)?, &indent));
+                    }
+
+                    items.push(item);
+                    index += 1;
+                }
+                None => index = remove_child(root, index),
+            }
+        }
+
+        Ok((
+            Self {
+                document,
+                items: items.clone(),
+            },
+            items,
+        ))
+    }
+
+    /// Render the given items onto the original document in the given order.
+    ///
+    /// Changed items only update the attributes and child elements that changed,
+    /// see [`assign`] for how items are matched to their elements.
+    /// Removed items take their preceding whitespace with them, while comments
+    /// before them are kept.
+    pub fn render(&self, items: &[T]) -> Result<Document, anyhow::Error> {
+        let mut document = self.document.clone();
+        let assignments = assign(&self.items, items, str::to_ascii_lowercase);
+        arrange(
+            root_mut::<T>(&mut document)?,
+            T::ELEMENT,
+            &assignments,
+            |index, original, indent| {
+                let item = &items[index];
+
+                match original {
+                    Some((original, mut element)) => {
+                        if *item != self.items[original] {
+                            patch(
+                                &mut element,
+                                &to_element(&self.items[original])?,
+                                &to_element(item)?,
+                                indent,
+                            );
+                        }
+
+                        Ok(element)
+                    }
+                    None => Ok(indented(to_element(item)?, indent)),
+                }
+            },
+        )?;
+        Ok(document)
+    }
+}
+
+/// Map each of the given items to the index of the original item whose element it is written onto, if any.
+///
+/// Unchanged items keep their original element, even among several items of the same key.
+/// Changed items take the first remaining element of their key. An item only takes the
+/// element of an item of another key if it was evidently renamed, i.e. neither key occurs
+/// on the other side and the items are equal apart from their names.
+/// Keys are compared after applying `key`.
+pub fn assign<T, K>(originals: &[T], items: &[T], key: K) -> Vec<Option<usize>>
+where
+    T: Entry,
+    K: Fn(&str) -> String,
+{
+    let mut by_key: HashMap<String, Vec<usize>> = HashMap::new();
+
+    for (index, original) in originals.iter().enumerate() {
+        by_key.entry(key(original.key())).or_default().push(index);
+    }
+
+    let mut used = vec![false; originals.len()];
+    let mut assignments: Vec<Option<usize>> = items
+        .iter()
+        .map(|item| {
+            let index = by_key
+                .get(&key(item.key()))?
+                .iter()
+                .copied()
+                .find(|&index| !used[index] && originals[index] == *item)?;
+            used[index] = true;
+            Some(index)
+        })
+        .collect();
+
+    for (item, assignment) in items.iter().zip(assignments.iter_mut()) {
+        if assignment.is_none() {
+            *assignment = by_key
+                .get(&key(item.key()))
+                .and_then(|indices| indices.iter().copied().find(|&index| !used[index]));
+            assignment.iter().for_each(|&index| used[index] = true);
+        }
+    }
+
+    let kept: HashSet<String> = items.iter().map(|item| key(item.key())).collect();
+
+    for (position, (item, assignment)) in items.iter().zip(assignments.iter_mut()).enumerate() {
+        if assignment.is_some() || by_key.contains_key(&key(item.key())) {
+            continue;
+        }
+
+        let renamed = |&index: &usize| {
+            originals.get(index).is_some_and(|original| {
+                let mut renamed = original.clone();
+                renamed.set_key(item.key());
+                !used[index] && !kept.contains(&key(original.key())) && renamed == *item
+            })
+        };
+        *assignment = Some(position)
+            .filter(renamed)
+            .or_else(|| (0..originals.len()).find(renamed));
+        assignment.iter().for_each(|&index| used[index] = true);
+    }
+
+    assignments
+}
+
+/// Lay out the `name` child elements of `root` in the order of the given assignments.
+///
+/// `build` is called with the index of each item, its original index and element if it
+/// was assigned one, and the indentation of the elements, and returns the element to write.
+/// Elements keep the comments and whitespace preceding them. Those of unassigned elements
+/// are kept in place apart from the whitespace directly preceding the element.
+/// New elements are written on a line of their own.
+pub fn arrange<F>(
+    root: &mut Element,
+    name: &str,
+    assignments: &[Option<usize>],
+    mut build: F,
+) -> Result<(), anyhow::Error>
+where
+    F: FnMut(usize, Option<(usize, Element)>, &str) -> Result<Element, anyhow::Error>,
+{
+    let mut segments = Vec::new();
+    let mut trivia = Vec::new();
+    let default_indent = root.child_indent().unwrap_or(DEFAULT_INDENT).to_string();
+
+    for node in std::mem::take(root.children_mut()) {
+        match node {
+            Node::Element(element) if element.name() == name => {
+                segments.push((std::mem::take(&mut trivia), element));
+            }
+            other => trivia.push(other),
+        }
+    }
+
+    let indent = segments
+        .first()
+        .and_then(|(trivia, _)| trivia.last())
+        .and_then(Node::as_whitespace)
+        .map_or(default_indent, ToString::to_string);
+    let mut used = vec![false; segments.len()];
+
+    for &index in assignments.iter().flatten() {
+        if let Some(used) = used.get_mut(index) {
+            *used = true;
+        }
+    }
+
+    let mut carried = Vec::new();
+    let mut slots: Vec<Option<(Vec<Node>, Element)>> = Vec::with_capacity(segments.len());
+
+    for ((mut trivia, element), used) in segments.into_iter().zip(used) {
+        if used {
+            carried.append(&mut trivia);
+            slots.push(Some((std::mem::take(&mut carried), element)));
+        } else {
+            if trivia.last().and_then(Node::as_whitespace).is_some() {
+                trivia.pop();
+            }
+
+            carried.append(&mut trivia);
+            slots.push(None);
+        }
+    }
+
+    let children = root.children_mut();
+
+    for (index, assignment) in assignments.iter().enumerate() {
+        if let Some((original, (trivia, element))) = assignment.and_then(|original| {
+            slots
+                .get_mut(original)
+                .and_then(Option::take)
+                .map(|slot| (original, slot))
+        }) {
+            children.extend(trivia);
+            children.push(Node::Element(build(
+                index,
+                Some((original, element)),
+                &indent,
+            )?));
+        } else {
+            children.push(Node::Text(indent.clone()));
+            children.push(Node::Element(build(index, None, &indent)?));
+        }
+    }
+
+    children.append(&mut carried);
+
+    if !assignments.is_empty() && trivia.first().and_then(Node::as_whitespace).is_none() {
+        children.push(Node::Text("\n".to_string()));
+    }
+
+    children.append(&mut trivia);
+    Ok(())
+}
+
+fn root<T: Entry>(document: &Document) -> Result<&Element, anyhow::Error> {
+    document
+        .root()
+        .filter(|root| root.name() == T::ROOT)
+        .ok_or_else(|| anyhow::Error::msg(format!("missing root element <{}>", T::ROOT)))
+}
+
+fn root_mut<T: Entry>(document: &mut Document) -> Result<&mut Element, anyhow::Error> {
+    document
+        .root_mut()
+        .filter(|root| root.name() == T::ROOT)
+        .ok_or_else(|| anyhow::Error::msg(format!("missing root element <{}>", T::ROOT)))
+}
+
+fn parse<T: Entry>(element: &Element) -> Result<T, anyhow::Error> {
+    Ok(quick_xml::de::from_str(&element.to_string())?)
+}
+
+fn to_element<T: Entry>(item: &T) -> Result<Element, anyhow::Error> {
+    item.to_xml()?.parse()
+}
+
+fn located<T: Entry>(element: &Element, error: &anyhow::Error) -> anyhow::Error {
+    let name = element.attribute("name").unwrap_or_default();
+    anyhow::Error::msg(element.position().map_or_else(
+        || format!("{} {name}: {error}", T::ELEMENT),
+        |position| format!("{} {name} at {position}: {error}", T::ELEMENT),
+    ))
+}
+
+/// Apply the differences between `old` and `new` onto `element`, which `old` was read from.
+///
+/// Child elements are matched by name and order, so that unchanged children,
+/// comments and the order of differently named children are kept.
+fn patch(element: &mut Element, old: &Element, new: &Element, indent: &str) {
+    for name in new.attribute_names() {
+        let value = new.attribute(name);
+
+        if old.attribute(name) != value {
+            element.set_attribute(name, &value.unwrap_or_default());
+        }
+    }
+
+    for name in old.attribute_names() {
+        if new.attribute(name).is_none() {
+            element.remove_attribute(name);
+        }
+    }
+
+    if old.elements().next().is_none() && new.elements().next().is_none() {
+        if old.text() != new.text() {
+            element.set_text(&new.text());
+        }
+
+        return;
+    }
+
+    let child_indent = element
+        .child_indent()
+        .map_or_else(|| format!("{indent}    "), ToString::to_string);
+    let mut names: Vec<&str> = Vec::new();
+
+    for child in new.elements().chain(old.elements()) {
+        if !names.contains(&child.name()) {
+            names.push(child.name());
+        }
+    }
+
+    for (index, name) in names.iter().enumerate() {
+        let olds: Vec<&Element> = named(old, name).collect();
+        let news: Vec<&Element> = named(new, name).collect();
+
+        if olds.len() != news.len()
+            || olds
+                .iter()
+                .zip(&news)
+                .any(|(old, new)| old.to_string() != new.to_string())
+        {
+            patch_children(element, &olds, &news, &names[..index], &child_indent);
+        }
+    }
+
+    if element
+        .children()
+        .last()
+        .and_then(Node::as_whitespace)
+        .is_none()
+        && element.elements().next().is_some()
+    {
+        element.children_mut().push(Node::Text(indent.to_string()));
+    }
+}
+
+/// Update the child elements of one name, which were read as `olds`, to `news`.
+///
+/// Unchanged children stay as they are, changed ones are patched in order,
+/// surplus ones are removed and missing ones are added after the last one.
+/// If there is none, they are added after the last child named in `preceding`.
+fn patch_children(
+    element: &mut Element,
+    olds: &[&Element],
+    news: &[&Element],
+    preceding: &[&str],
+    indent: &str,
+) {
+    let Some(name) = news
+        .first()
+        .or_else(|| olds.first())
+        .map(|child| child.name().to_string())
+    else {
+        return;
+    };
+    let mut used = vec![false; olds.len()];
+    let mut assignments: Vec<Option<usize>> = news
+        .iter()
+        .map(|new| {
+            let index = (0..olds.len())
+                .find(|&index| !used[index] && olds[index].to_string() == new.to_string())?;
+            used[index] = true;
+            Some(index)
+        })
+        .collect();
+
+    for assignment in &mut assignments {
+        if assignment.is_none() {
+            *assignment = (0..olds.len()).find(|&index| !used[index]);
+            assignment.iter().for_each(|&index| used[index] = true);
+        }
+    }
+
+    let positions: Vec<usize> = element
+        .children()
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.is_element_named(&name))
+        .map(|(position, _)| position)
+        .collect();
+
+    for (new, &assignment) in news.iter().zip(&assignments) {
+        let Some(index) = assignment else {
+            continue;
+        };
+
+        if let Some(child) = positions
+            .get(index)
+            .and_then(|&position| element.children_mut()[position].as_element_mut())
+        {
+            patch(child, olds[index], new, indent);
+        }
+    }
+
+    let mut insertion = positions
+        .last()
+        .copied()
+        .or_else(|| {
+            element
+                .children()
+                .iter()
+                .rposition(|node| preceding.iter().any(|name| node.is_element_named(name)))
+        })
+        .map_or_else(|| insertion_index(element), |position| position + 1);
+
+    for (new, _) in news
+        .iter()
+        .zip(&assignments)
+        .filter(|(_, assignment)| assignment.is_none())
+    {
+        let mut child = indented((*new).clone(), indent);
+        child.clear_spans();
+        element.children_mut().splice(
+            insertion..insertion,
+            [Node::Text(indent.to_string()), Node::Element(child)],
+        );
+        insertion += 2;
+    }
+
+    for (&position, _) in positions.iter().zip(&used).filter(|(_, &used)| !used).rev() {
+        remove_child(element, position);
+    }
+}
+
+fn named<'element>(
+    element: &'element Element,
+    name: &'element str,
+) -> impl Iterator<Item = &'element Element> {
+    element.elements().filter(move |child| child.name() == name)
+}
+
+/// Return the index before the whitespace preceding the element's closing tag.
+fn insertion_index(element: &Element) -> usize {
+    match element.children().last() {
+        Some(node) if node.as_whitespace().is_some() => element.children().len() - 1,
+        _ => element.children().len(),
+    }
+}
+
+/// Remove the child at the given index along with its preceding whitespace.
+///
+/// Returns the index of the node that followed the removed child.
+fn remove_child(element: &mut Element, index: usize) -> usize {
+    let children = element.children_mut();
+    children.remove(index);
+
+    if index > 0 && children[index - 1].as_whitespace().is_some() {
+        children.remove(index - 1);
+        index - 1
+    } else {
+        index
+    }
+}
+
+/// Lay out the element's descendants one per line, indented relative to `indent`.
+fn indented(mut element: Element, indent: &str) -> Element {
+    if element.elements().next().is_none() {
+        return element;
+    }
+
+    let child_indent = format!("{indent}    ");
+    let children: Vec<Node> = std::mem::take(element.children_mut())
+        .into_iter()
+        .filter(|node| node.as_whitespace().is_none())
+        .collect();
+
+    for child in children {
+        element
+            .children_mut()
+            .push(Node::Text(child_indent.clone()));
+        element.children_mut().push(match child {
+            Node::Element(child) => Node::Element(indented(child, &child_indent)),
+            other => other,
+        });
+    }
+
+    element.children_mut().push(Node::Text(indent.to_string()));
+    element
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+    use crate::spawnabletypes::SpawnableType;
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<spawnabletypes>
+    <!-- Weapons -->
+    <type name="AK101">
+        <attachments chance="0.30">
+            <item name="AK_WoodBttstck" chance="1.00" />
+        </attachments>
+        <cargo preset="mixArmy" />
+        <attachments preset="akmOptic" />
+    </type>
+    <!-- Barrels -->
+    <type name="Barrel_Blue">
+        <hoarder />
+    </type>
+</spawnabletypes>
+"#;
+
+    fn parse() -> (Source<SpawnableType>, Vec<SpawnableType>) {
+        Source::parse(DOCUMENT.parse().unwrap()).unwrap()
+    }
+
+    fn render(source: &Source<SpawnableType>, items: &[SpawnableType]) -> String {
+        source.render(items).unwrap().to_string()
+    }
+
+    #[test]
+    fn writes_unchanged_items_as_read() {
+        let (source, items) = parse();
+        assert_eq!(render(&source, &items), DOCUMENT);
+    }
+
+    #[test]
+    fn keeps_the_order_of_unchanged_children() {
+        let (source, mut items) = parse();
+        items[0].mut_cargo().clear();
+        assert_eq!(
+            render(&source, &items),
+            DOCUMENT.replace("        <cargo preset=\"mixArmy\" />\n", "")
+        );
+    }
+
+    #[test]
+    fn keeps_the_element_of_renamed_items() {
+        let (source, mut items) = parse();
+        items[1].set_name("Barrel_Red");
+        assert_eq!(
+            render(&source, &items),
+            DOCUMENT.replace("Barrel_Blue", "Barrel_Red")
+        );
+    }
+
+    #[test]
+    fn writes_added_items_into_new_elements() {
+        let (source, mut items) = parse();
+        items.remove(0);
+        items.push(SpawnableType::new("AK74"));
+        let rendered = render(&source, &items);
+        assert!(rendered.contains("    </type>\n    <type name=\"AK74\"/>\n</spawnabletypes>"));
+        assert!(!rendered.contains("AK101"));
+        assert!(!rendered.contains("mixArmy"));
+    }
+
+    #[test]
+    fn keeps_the_given_order() {
+        let (source, mut items) = parse();
+        items.reverse();
+        let rendered = render(&source, &items);
+        let position = |text| rendered.find(text).unwrap();
+        assert!(position("<!-- Barrels -->") < position("Barrel_Blue"));
+        assert!(position("Barrel_Blue") < position("<!-- Weapons -->"));
+        assert!(position("<!-- Weapons -->") < position("AK101"));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::document::Position;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// The range an element occupies in a document, from its start tag to the end of its closing tag
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Span {
+    start: Position,
+    end: Position,
+}
+
+impl Span {
+    #[must_use]
+    pub const fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    #[must_use]
+    pub const fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Return the position right after the element.
+    #[must_use]
+    pub const fn end(&self) -> Position {
+        self.end
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
@@ -0,0 +1,397 @@
+//! Parsing and editing of `cfgeconomycore.xml`.
+
+mod registration;
+
+use crate::document::{Document, Element, Node};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+pub use registration::Registration;
+
+/// The file name of the economy core configuration within a mission directory.
+pub const FILE_NAME: &str = "cfgeconomycore.xml";
+
+/// The kinds of files that can be registered in a `<ce>` folder.
+pub const KINDS: [&str; 3] = ["types", "spawnabletypes", "events"];
+
+const DEFAULT_INDENT: &str = "\n    ";
+
+/// The whitespace before the end of new `<file>` tags if there are none to copy it from.
+const DEFAULT_PADDING: &str = " ";
+
+/// The central economy's configuration of a mission
+///
+/// Backed by the original document, so that editing the `<ce>` registrations
+/// keeps the classes, defaults, comments and formatting as they are.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EconomyCore {
+    document: Document,
+}
+
+impl EconomyCore {
+    /// Read the economy core configuration from the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file cannot be read or is malformed.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        std::fs::read_to_string(filename)?.parse()
+    }
+
+    /// Write the economy core configuration to the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to write.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file cannot be written.
+    pub fn write(&self, filename: &str) -> Result<(), anyhow::Error> {
+        Ok(std::fs::write(filename, self.to_string())?)
+    }
+
+    /// Return the files registered in the `<ce>` folders in the order the game loads them.
+    #[must_use]
+    pub fn registrations(&self) -> Vec<Registration> {
+        self.folders()
+            .flat_map(|folder| {
+                let name = folder.attribute("folder").unwrap_or_default();
+                files(folder)
+                    .map(move |file| {
+                        Registration::new(
+                            &name,
+                            &file.attribute("name").unwrap_or_default(),
+                            &file.attribute("type").unwrap_or_default(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Return whether the given file is registered in the given folder.
+    #[must_use]
+    pub fn is_registered(&self, folder: &str, name: &str) -> bool {
+        self.registrations()
+            .iter()
+            .any(|registration| registration.matches(folder, name))
+    }
+
+    /// Register the given file.
+    ///
+    /// The file is appended to the last `<ce>` block of its folder.
+    /// If there is none, a new block is added after the last `<ce>` block.
+    /// The new `<file>` tag is spaced like the existing ones.
+    ///
+    /// Returns `false` if the file is already registered in that folder.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the kind of file is unknown.
+    pub fn register(&mut self, registration: &Registration) -> Result<bool, anyhow::Error> {
+        if !KINDS.contains(&registration.kind()) {
+            return Err(anyhow::Error::msg(format!(
+                "unknown file type \"{}\", expected one of: {}",
+                registration.kind(),
+                KINDS.join(", ")
+            )));
+        }
+
+        if self.is_registered(registration.folder(), registration.name()) {
+            return Ok(false);
+        }
+
+        let padding = self
+            .folders()
+            .flat_map(files)
+            .next()
+            .map_or(DEFAULT_PADDING, Element::padding)
+            .to_string();
+        let root = self.root_mut()?;
+        let indent = root.child_indent().unwrap_or(DEFAULT_INDENT).to_string();
+        let mut file = Element::new("file");
+        file.set_attribute("name", registration.name());
+        file.set_attribute("type", registration.kind());
+        file.set_padding(&padding);
+
+        if let Some(folder) = root
+            .elements_mut()
+            .filter(|element| is_folder(element, registration.folder()))
+            .last()
+        {
+            let child_indent = folder
+                .child_indent()
+                .map_or_else(|| format!("{indent}    "), ToString::to_string);
+            let index = last_element_index(folder).map_or(0, |index| index + 1);
+
+            if folder.children().is_empty() {
+                folder.children_mut().push(Node::Text(indent));
+            }
+
+            folder.children_mut().splice(
+                index..index,
+                [Node::Text(child_indent), Node::Element(file)],
+            );
+            return Ok(true);
+        }
+
+        let mut folder = Element::new("ce");
+        folder.set_attribute("folder", registration.folder());
+        folder.children_mut().extend([
+            Node::Text(format!("{indent}    ")),
+            Node::Element(file),
+            Node::Text(indent.clone()),
+        ]);
+        let index = root
+            .children()
+            .iter()
+            .rposition(|node| node.is_element_named("ce"))
+            .or_else(|| last_element_index(root))
+            .map_or(0, |index| index + 1);
+
+        if root.children().is_empty() {
+            root.children_mut().push(Node::Text("\n".to_string()));
+        }
+
+        root.children_mut()
+            .splice(index..index, [Node::Text(indent), Node::Element(folder)]);
+        Ok(true)
+    }
+
+    /// Remove the given file from the given folder.
+    ///
+    /// `<ce>` blocks left without any files are removed as well.
+    ///
+    /// Returns the removed registration, if the file was registered.
+    pub fn unregister(&mut self, folder: &str, name: &str) -> Option<Registration> {
+        let root = self.document.root_mut()?;
+        let (block_index, file_index) =
+            root.children()
+                .iter()
+                .enumerate()
+                .find_map(|(index, node)| {
+                    node.as_element()
+                        .filter(|element| is_folder(element, folder))
+                        .and_then(|block| {
+                            block.children().iter().position(|node| {
+                                node.as_element()
+                                    .is_some_and(|element| is_file(element, name))
+                            })
+                        })
+                        .map(|file_index| (index, file_index))
+                })?;
+        let block = root.children_mut()[block_index].as_element_mut()?;
+        let file = remove_child(block, file_index);
+        let registration = file.as_element().map(|file| {
+            Registration::new(
+                &block.attribute("folder").unwrap_or_default(),
+                &file.attribute("name").unwrap_or_default(),
+                &file.attribute("type").unwrap_or_default(),
+            )
+        });
+
+        if block.elements().next().is_none() {
+            remove_child(root, block_index);
+        }
+
+        registration
+    }
+
+    /// Return the registered files that do not exist within the given mission directory.
+    #[must_use]
+    pub fn missing(&self, root: &Path) -> Vec<Registration> {
+        self.registrations()
+            .into_iter()
+            .filter(|registration| !registration.path(root).is_file())
+            .collect()
+    }
+
+    fn folders(&self) -> impl Iterator<Item = &Element> {
+        self.document
+            .root()
+            .into_iter()
+            .flat_map(Element::elements)
+            .filter(|element| element.name() == "ce")
+    }
+
+    fn root_mut(&mut self) -> Result<&mut Element, anyhow::Error> {
+        self.document
+            .root_mut()
+            .ok_or_else(|| anyhow::Error::msg("missing root element <economycore>"))
+    }
+}
+
+impl Display for EconomyCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.document)
+    }
+}
+
+impl FromStr for EconomyCore {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let economy_core = Self {
+            document: text.parse()?,
+        };
+
+        if economy_core
+            .document
+            .root()
+            .is_none_or(|root| root.name() != "economycore")
+        {
+            return Err(anyhow::Error::msg("missing root element <economycore>"));
+        }
+
+        for folder in economy_core.folders() {
+            require(folder, "folder")?;
+
+            for file in files(folder) {
+                require(file, "name")?;
+                require(file, "type")?;
+            }
+        }
+
+        Ok(economy_core)
+    }
+}
+
+fn files(folder: &Element) -> impl Iterator<Item = &Element> {
+    folder.elements().filter(|element| element.name() == "file")
+}
+
+fn is_folder(element: &Element, folder: &str) -> bool {
+    element.name() == "ce"
+        && element
+            .attribute("folder")
+            .is_some_and(|other| same_folder(&other, folder))
+}
+
+fn is_file(element: &Element, name: &str) -> bool {
+    element.name() == "file"
+        && element
+            .attribute("name")
+            .is_some_and(|other| other.eq_ignore_ascii_case(name))
+}
+
+/// Compare two folders case-insensitively, ignoring trailing slashes.
+fn same_folder(lhs: &str, rhs: &str) -> bool {
+    lhs.trim_end_matches(['/', '\\'])
+        .eq_ignore_ascii_case(rhs.trim_end_matches(['/', '\\']))
+}
+
+fn last_element_index(element: &Element) -> Option<usize> {
+    element
+        .children()
+        .iter()
+        .rposition(|node| node.as_element().is_some())
+}
+
+/// Remove the child at the given index along with its preceding whitespace.
+fn remove_child(element: &mut Element, index: usize) -> Node {
+    let children = element.children_mut();
+    let node = children.remove(index);
+
+    if index > 0 && children[index - 1].as_whitespace().is_some() {
+        children.remove(index - 1);
+    }
+
+    node
+}
+
+fn require(element: &Element, attribute: &str) -> Result<(), anyhow::Error> {
+    if element.attribute(attribute).is_some() {
+        return Ok(());
+    }
+
+    let message = format!("<{}> is missing attribute \"{attribute}\"", element.name());
+    Err(anyhow::Error::msg(element.position().map_or_else(
+        || message.clone(),
+        |position| format!("{position}: {message}"),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EconomyCore, Registration};
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<economycore>
+    <classes>
+        <rootclass name="DefaultWeapon" />
+    </classes>
+    <ce folder="custom">
+        <file name="mytypes.xml" type="types" />
+    </ce>
+    <!-- events -->
+    <ce folder="custom">
+        <file name="myevents.xml" type="events" />
+    </ce>
+</economycore>
+"#;
+
+    fn economy_core() -> EconomyCore {
+        DOCUMENT.parse().unwrap()
+    }
+
+    #[test]
+    fn writes_unchanged_configurations_as_read() {
+        assert_eq!(economy_core().to_string(), DOCUMENT);
+    }
+
+    #[test]
+    fn registers_files_in_the_last_block_of_their_folder() {
+        let mut economy_core = economy_core();
+        assert!(economy_core
+            .register(&Registration::new("custom", "more.xml", "types"))
+            .unwrap());
+        assert_eq!(
+            economy_core.to_string(),
+            DOCUMENT.replace(
+                "        <file name=\"myevents.xml\" type=\"events\" />\n",
+                "        <file name=\"myevents.xml\" type=\"events\" />\n        <file name=\"more.xml\" type=\"types\" />\n"
+            )
+        );
+    }
+
+    #[test]
+    fn registers_files_of_new_folders_in_new_blocks() {
+        let mut economy_core = economy_core();
+        assert!(economy_core
+            .register(&Registration::new("other", "types.xml", "types"))
+            .unwrap());
+        assert_eq!(
+            economy_core.to_string(),
+            DOCUMENT.replace(
+                "    </ce>\n</economycore>",
+                "    </ce>\n    <ce folder=\"other\">\n        <file name=\"types.xml\" type=\"types\" />\n    </ce>\n</economycore>"
+            )
+        );
+    }
+
+    #[test]
+    fn ignores_registered_files() {
+        let mut economy_core = economy_core();
+        assert!(!economy_core
+            .register(&Registration::new("custom/", "MyTypes.xml", "types"))
+            .unwrap());
+        assert_eq!(economy_core.to_string(), DOCUMENT);
+    }
+
+    #[test]
+    fn unregisters_files_along_with_emptied_blocks() {
+        let mut economy_core = economy_core();
+        assert_eq!(
+            economy_core.unregister("custom", "myevents.xml"),
+            Some(Registration::new("custom", "myevents.xml", "events"))
+        );
+        assert_eq!(
+            economy_core.to_string(),
+            DOCUMENT.replace(
+                "    <ce folder=\"custom\">\n        <file name=\"myevents.xml\" type=\"events\" />\n    </ce>\n",
+                ""
+            )
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// A file registered in a `<ce>` folder of `cfgeconomycore.xml`
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Registration {
+    folder: String,
+    name: String,
+    kind: String,
+}
+
+impl Registration {
+    #[must_use]
+    pub fn new(folder: &str, name: &str, kind: &str) -> Self {
+        Self {
+            folder: folder.to_string(),
+            name: name.to_string(),
+            kind: kind.to_string(),
+        }
+    }
+
+    /// Return the folder relative to the mission directory.
+    #[must_use]
+    pub fn folder(&self) -> &str {
+        self.folder.as_str()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Return the kind of file, e.g. `types`, `spawnabletypes` or `events`.
+    #[must_use]
+    pub fn kind(&self) -> &str {
+        self.kind.as_str()
+    }
+
+    /// Return whether this registration refers to the given file in the given folder.
+    ///
+    /// Folders and file names are compared case-insensitively, ignoring trailing slashes.
+    #[must_use]
+    pub fn matches(&self, folder: &str, name: &str) -> bool {
+        super::same_folder(&self.folder, folder) && self.name.eq_ignore_ascii_case(name)
+    }
+
+    /// Return the path of the registered file within the given mission directory.
+    #[must_use]
+    pub fn path(&self, root: &Path) -> PathBuf {
+        root.join(&self.folder).join(&self.name)
+    }
+}
+
+impl Display for Registration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{} ({})", self.folder, self.name, self.kind)
+    }
+}
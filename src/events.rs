@@ -0,0 +1,291 @@
+//! Parsing and editing of `events.xml`.
+
+mod child;
+mod event;
+mod flags;
+mod limit;
+mod position;
+mod raw;
+
+use crate::document::{Document, Source};
+use serde::{Deserialize, Serialize};
+use serde_rw::{FromFile, ToXml};
+use std::fmt::{Display, Formatter};
+use std::slice::{Iter, IterMut};
+use std::str::FromStr;
+
+pub use child::Child;
+pub use event::Event;
+pub use flags::Flags;
+pub use limit::Limit;
+pub use position::Position;
+
+const DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#;
+
+/// The dynamic events of a mission
+///
+/// If read from a well-formed document, that document is kept, so that comments,
+/// unknown elements and formatting survive writing the events back.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "events")]
+pub struct Events {
+    #[serde(rename = "event", default)]
+    events: Vec<Event>,
+    #[serde(skip)]
+    source: Option<Source<Event>>,
+}
+
+impl Events {
+    /// Parse the events.xml strictly
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file is not well-formed or an event cannot be deserialized.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        std::fs::read_to_string(filename)?.parse()
+    }
+
+    /// Parse the events.xml gracefully
+    ///
+    /// Nameless events and children are dropped and missing or invalid values are
+    /// replaced with defaults, which may lead to data loss.
+    /// If the file is not well-formed XML, its comments and formatting are not preserved.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` holding both the document and the deserialization error
+    /// if the deserialization fails.
+    pub fn read_gracefully(filename: &str) -> Result<Self, anyhow::Error> {
+        match Document::read(filename)
+            .and_then(|document| Source::recover(document, Event::recover_element))
+        {
+            Ok((source, events)) => Ok(Self {
+                events,
+                source: Some(source),
+            }),
+            Err(error) => raw::Events::from_file(filename)
+                .map(Self::from)
+                .map_err(|raw_error| anyhow::Error::msg(format!("{error}\n{raw_error}"))),
+        }
+    }
+
+    /// Write the events.xml to the given file.
+    ///
+    /// Comments and formatting of the file the events were read from are preserved.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to write.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if serialization or writing the file fails.
+    pub fn write(&self, filename: &str) -> Result<(), anyhow::Error> {
+        Ok(std::fs::write(filename, self.render()?)?)
+    }
+
+    pub fn events(&self) -> Iter<'_, Event> {
+        self.events.iter()
+    }
+
+    pub fn mut_events(&mut self) -> IterMut<'_, Event> {
+        self.events.iter_mut()
+    }
+
+    /// Return the event with the given name.
+    ///
+    /// Names are compared case-insensitively, like the game does.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Event> {
+        self.position(name).map(|position| &self.events[position])
+    }
+
+    /// Return the event with the given name mutably.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Event> {
+        self.position(name)
+            .map(|position| &mut self.events[position])
+    }
+
+    /// Return whether there is an event with the given name.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    /// Add the event unless there already is an event with the same name.
+    pub fn add(&mut self, event: Event) {
+        if !self.contains(event.get_name()) {
+            self.events.push(event);
+        }
+    }
+
+    /// Replace the event with the same name or add it if there is none.
+    ///
+    /// Returns the replaced event.
+    pub fn insert(&mut self, event: Event) -> Option<Event> {
+        if let Some(position) = self.position(event.get_name()) {
+            Some(std::mem::replace(&mut self.events[position], event))
+        } else {
+            self.events.push(event);
+            None
+        }
+    }
+
+    /// Remove the event with the given name.
+    ///
+    /// Returns the removed event.
+    pub fn remove(&mut self, name: &str) -> Option<Event> {
+        self.position(name)
+            .map(|position| self.events.remove(position))
+    }
+
+    /// Remove all events not satisfying the predicate.
+    ///
+    /// Returns the removed events.
+    pub fn retain<F>(&mut self, mut predicate: F) -> Vec<Event>
+    where
+        F: FnMut(&Event) -> bool,
+    {
+        let (kept, removed) = std::mem::take(&mut self.events)
+            .into_iter()
+            .partition(|event| predicate(event));
+        self.events = kept;
+        removed
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.events
+            .iter()
+            .position(|event| event.get_name().eq_ignore_ascii_case(name))
+    }
+
+    fn render(&self) -> Result<String, anyhow::Error> {
+        self.source.as_ref().map_or_else(
+            || Ok(format!("{DECLARATION}\n{}\n", self.to_xml_pretty(' ', 4)?)),
+            |source| Ok(source.render(&self.events)?.to_string()),
+        )
+    }
+}
+
+impl Display for Events {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render().map_err(|_| std::fmt::Error)?)
+    }
+}
+
+impl FromStr for Events {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (source, events) = Source::parse(s.parse()?)?;
+        Ok(Self {
+            events,
+            source: Some(source),
+        })
+    }
+}
+
+impl PartialEq for Events {
+    fn eq(&self, other: &Self) -> bool {
+        self.events == other.events
+    }
+}
+
+impl Eq for Events {}
+
+impl From<raw::Events> for Events {
+    fn from(raw: raw::Events) -> Self {
+        Self {
+            events: raw.events.into_iter().filter_map(Event::recover).collect(),
+            source: None,
+        }
+    }
+}
+
+/// Parse a non-negative integer.
+fn parse_number(field: &str, text: Option<&str>) -> Result<u32, anyhow::Error> {
+    let text = text.ok_or_else(|| anyhow::Error::msg(format!("{field} is missing")))?;
+    text.parse()
+        .map_err(|_| anyhow::Error::msg(format!("{field} {text:?} is not a non-negative integer")))
+}
+
+/// Parse a non-negative integer leniently, returning 0 if it is missing or invalid.
+///
+/// Surrounding whitespace is ignored and integral floats such as `1e4` are accepted.
+fn recover_number(text: Option<&str>) -> u32 {
+    text.map(str::trim)
+        .and_then(|text| {
+            text.parse().ok().or_else(|| {
+                text.parse::<f64>()
+                    .ok()
+                    .filter(|float| float.fract() == 0.0)
+                    .and_then(|float| {
+                        #[allow(clippy::cast_possible_truncation)]
+                        u32::try_from(float as i64).ok()
+                    })
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// Parse a flag given as `0` or `1`.
+fn parse_flag(field: &str, text: Option<&str>) -> Result<bool, anyhow::Error> {
+    match text {
+        Some("0") => Ok(false),
+        Some("1") => Ok(true),
+        Some(text) => Err(anyhow::Error::msg(format!(
+            "{field} {text:?} is neither 0 nor 1"
+        ))),
+        None => Err(anyhow::Error::msg(format!("{field} is missing"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Event, Events};
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<events>
+    <!-- Animals -->
+    <event name="AmbientFox">
+        <nominal>0</nominal>
+        <min>0</min>
+        <max>0</max>
+        <lifetime>180</lifetime>
+        <restock>0</restock>
+        <saferadius>200</saferadius>
+        <distanceradius>0</distanceradius>
+        <cleanupradius>0</cleanupradius>
+        <flags deletable="0" init_random="0" remove_damaged="1"/>
+        <position>player</position>
+        <limit>custom</limit>
+        <active>1</active>
+        <children>
+            <!-- vanilla fox -->
+            <child lootmax="0" lootmin="0" max="1" min="1" type="Animal_VulpesVulpes"/>
+        </children>
+    </event>
+</events>
+"#;
+
+    #[test]
+    fn writes_unchanged_events_as_read() {
+        let events: Events = DOCUMENT.parse().unwrap();
+        assert_eq!(events.to_string(), DOCUMENT);
+    }
+
+    #[test]
+    fn writes_events_replacing_removed_ones_into_new_elements() {
+        let mut events: Events = DOCUMENT.parse().unwrap();
+        events.remove("AmbientFox");
+        events.add(Event::new("AmbientWolf"));
+        let rendered = events.to_string();
+        assert!(rendered.contains("<!-- Animals -->"));
+        assert!(rendered.contains("<event name=\"AmbientWolf\">"));
+        assert!(rendered.contains("<children/>"));
+        assert!(!rendered.contains("Animal_VulpesVulpes"));
+        assert!(!rendered.contains("<!-- vanilla fox -->"));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::events::{parse_number, raw, recover_number};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A class spawned by an event
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "raw::Child")]
+pub struct Child {
+    #[serde(rename = "@lootmax")]
+    lootmax: u32,
+    #[serde(rename = "@lootmin")]
+    lootmin: u32,
+    #[serde(rename = "@max")]
+    max: u32,
+    #[serde(rename = "@min")]
+    min: u32,
+    #[serde(rename = "@type")]
+    typ: String,
+}
+
+impl Child {
+    /// Create a child spawning exactly one item of the given class without loot.
+    #[must_use]
+    pub fn new(typ: &str) -> Self {
+        Self {
+            lootmax: 0,
+            lootmin: 0,
+            max: 1,
+            min: 1,
+            typ: typ.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn get_type(&self) -> &str {
+        self.typ.as_str()
+    }
+
+    #[must_use]
+    pub const fn get_min(&self) -> u32 {
+        self.min
+    }
+
+    #[must_use]
+    pub const fn get_max(&self) -> u32 {
+        self.max
+    }
+
+    #[must_use]
+    pub const fn get_lootmin(&self) -> u32 {
+        self.lootmin
+    }
+
+    #[must_use]
+    pub const fn get_lootmax(&self) -> u32 {
+        self.lootmax
+    }
+
+    pub fn set_type(&mut self, typ: &str) {
+        self.typ = typ.to_string();
+    }
+
+    pub fn set_min(&mut self, min: u32) {
+        self.min = min;
+    }
+
+    pub fn set_max(&mut self, max: u32) {
+        self.max = max;
+    }
+
+    pub fn set_lootmin(&mut self, lootmin: u32) {
+        self.lootmin = lootmin;
+    }
+
+    pub fn set_lootmax(&mut self, lootmax: u32) {
+        self.lootmax = lootmax;
+    }
+
+    /// Read the child, replacing invalid amounts with 0.
+    ///
+    /// Returns `None` if the child has no class.
+    pub(crate) fn recover(raw: raw::Child) -> Option<Self> {
+        let typ = raw.typ.filter(|typ| !typ.trim().is_empty())?;
+        Some(Self {
+            lootmax: recover_number(raw.lootmax.as_deref()),
+            lootmin: recover_number(raw.lootmin.as_deref()),
+            max: recover_number(raw.max.as_deref()),
+            min: recover_number(raw.min.as_deref()),
+            typ: typ.trim().to_string(),
+        })
+    }
+}
+
+impl Display for Child {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({} - {}, loot {} - {})",
+            self.typ, self.min, self.max, self.lootmin, self.lootmax
+        )
+    }
+}
+
+impl TryFrom<raw::Child> for Child {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Child) -> Result<Self, Self::Error> {
+        let typ = raw
+            .typ
+            .ok_or_else(|| anyhow::Error::msg("child without type"))?;
+        let context = |error: anyhow::Error| anyhow::Error::msg(format!("child {typ}: {error}"));
+        Ok(Self {
+            lootmax: parse_number("lootmax", raw.lootmax.as_deref()).map_err(context)?,
+            lootmin: parse_number("lootmin", raw.lootmin.as_deref()).map_err(context)?,
+            max: parse_number("max", raw.max.as_deref()).map_err(context)?,
+            min: parse_number("min", raw.min.as_deref()).map_err(context)?,
+            typ,
+        })
+    }
+}
@@ -0,0 +1,344 @@
+use crate::document::{Element, Entry};
+use crate::events::{parse_flag, parse_number, raw, recover_number, Child, Flags, Limit, Position};
+use crate::util::{as_int, parse_bool_or_false};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A dynamic event such as a vehicle, heli crash, animal or infected spawn
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "event", try_from = "raw::Event")]
+pub struct Event {
+    #[serde(rename = "@name")]
+    name: String,
+    nominal: u32,
+    min: u32,
+    max: u32,
+    lifetime: u32,
+    restock: u32,
+    saferadius: u32,
+    distanceradius: u32,
+    cleanupradius: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secondary: Option<String>,
+    flags: Flags,
+    position: Position,
+    limit: Limit,
+    #[serde(serialize_with = "as_int")]
+    active: bool,
+    children: Children,
+}
+
+impl Event {
+    /// Create an inactive event with all fields zeroed or unset.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            nominal: 0,
+            min: 0,
+            max: 0,
+            lifetime: 0,
+            restock: 0,
+            saferadius: 0,
+            distanceradius: 0,
+            cleanupradius: 0,
+            secondary: None,
+            flags: Flags::default(),
+            position: Position::default(),
+            limit: Limit::default(),
+            active: false,
+            children: Children::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    #[must_use]
+    pub const fn get_nominal(&self) -> u32 {
+        self.nominal
+    }
+
+    #[must_use]
+    pub const fn get_min(&self) -> u32 {
+        self.min
+    }
+
+    #[must_use]
+    pub const fn get_max(&self) -> u32 {
+        self.max
+    }
+
+    #[must_use]
+    pub const fn get_lifetime(&self) -> u32 {
+        self.lifetime
+    }
+
+    #[must_use]
+    pub const fn get_restock(&self) -> u32 {
+        self.restock
+    }
+
+    #[must_use]
+    pub const fn get_saferadius(&self) -> u32 {
+        self.saferadius
+    }
+
+    #[must_use]
+    pub const fn get_distanceradius(&self) -> u32 {
+        self.distanceradius
+    }
+
+    #[must_use]
+    pub const fn get_cleanupradius(&self) -> u32 {
+        self.cleanupradius
+    }
+
+    #[must_use]
+    pub fn get_secondary(&self) -> Option<&str> {
+        self.secondary.as_deref()
+    }
+
+    #[must_use]
+    pub const fn get_flags(&self) -> &Flags {
+        &self.flags
+    }
+
+    #[must_use]
+    pub const fn get_position(&self) -> Position {
+        self.position
+    }
+
+    #[must_use]
+    pub const fn get_limit(&self) -> Limit {
+        self.limit
+    }
+
+    #[must_use]
+    pub const fn get_active(&self) -> bool {
+        self.active
+    }
+
+    #[must_use]
+    pub fn get_children(&self) -> &[Child] {
+        &self.children.children
+    }
+
+    #[must_use]
+    pub fn mut_flags(&mut self) -> &mut Flags {
+        &mut self.flags
+    }
+
+    #[must_use]
+    pub fn mut_children(&mut self) -> &mut Vec<Child> {
+        &mut self.children.children
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn set_nominal(&mut self, nominal: u32) {
+        self.nominal = nominal;
+    }
+
+    pub fn set_min(&mut self, min: u32) {
+        self.min = min;
+    }
+
+    pub fn set_max(&mut self, max: u32) {
+        self.max = max;
+    }
+
+    pub fn set_lifetime(&mut self, lifetime: u32) {
+        self.lifetime = lifetime;
+    }
+
+    pub fn set_restock(&mut self, restock: u32) {
+        self.restock = restock;
+    }
+
+    pub fn set_saferadius(&mut self, saferadius: u32) {
+        self.saferadius = saferadius;
+    }
+
+    pub fn set_distanceradius(&mut self, distanceradius: u32) {
+        self.distanceradius = distanceradius;
+    }
+
+    pub fn set_cleanupradius(&mut self, cleanupradius: u32) {
+        self.cleanupradius = cleanupradius;
+    }
+
+    pub fn set_secondary(&mut self, secondary: Option<&str>) {
+        self.secondary = secondary.map(ToString::to_string);
+    }
+
+    pub fn set_flags(&mut self, flags: Flags) {
+        self.flags = flags;
+    }
+
+    pub fn set_position(&mut self, position: Position) {
+        self.position = position;
+    }
+
+    pub fn set_limit(&mut self, limit: Limit) {
+        self.limit = limit;
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    /// Read the event, replacing missing or invalid values with defaults.
+    ///
+    /// Returns `None` if the event has no name.
+    pub(crate) fn recover(raw: raw::Event) -> Option<Self> {
+        let name = raw.name.filter(|name| !name.trim().is_empty())?;
+        Some(Self {
+            name: name.trim().to_string(),
+            nominal: recover_number(raw.nominal.as_deref()),
+            min: recover_number(raw.min.as_deref()),
+            max: recover_number(raw.max.as_deref()),
+            lifetime: recover_number(raw.lifetime.as_deref()),
+            restock: recover_number(raw.restock.as_deref()),
+            saferadius: recover_number(raw.saferadius.as_deref()),
+            distanceradius: recover_number(raw.distanceradius.as_deref()),
+            cleanupradius: recover_number(raw.cleanupradius.as_deref()),
+            secondary: raw
+                .secondary
+                .map(|secondary| secondary.trim().to_string())
+                .filter(|secondary| !secondary.is_empty()),
+            flags: raw.flags.map(Flags::recover).unwrap_or_default(),
+            position: raw
+                .position
+                .and_then(|position| position.parse().ok())
+                .unwrap_or_default(),
+            limit: raw
+                .limit
+                .and_then(|limit| limit.parse().ok())
+                .unwrap_or_default(),
+            active: raw
+                .active
+                .is_some_and(|active| parse_bool_or_false(active.trim())),
+            children: Children {
+                children: raw
+                    .children
+                    .unwrap_or_default()
+                    .children
+                    .into_iter()
+                    .filter_map(Child::recover)
+                    .collect(),
+            },
+        })
+    }
+
+    /// Read the event from its XML element gracefully, see [`Event::recover`].
+    pub(crate) fn recover_element(element: &Element) -> Result<Option<Self>, anyhow::Error> {
+        Ok(Self::recover(quick_xml::de::from_str(
+            &element.to_string(),
+        )?))
+    }
+}
+
+impl Entry for Event {
+    const ROOT: &'static str = "events";
+    const ELEMENT: &'static str = "event";
+
+    fn key(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn set_key(&mut self, key: &str) {
+        self.set_name(key);
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "name          :\t{}", self.name)?;
+        write!(f, "\nnominal       :\t{}", self.nominal)?;
+        write!(f, "\nmin           :\t{}", self.min)?;
+        write!(f, "\nmax           :\t{}", self.max)?;
+        write!(f, "\nlifetime      :\t{}", self.lifetime)?;
+        write!(f, "\nrestock       :\t{}", self.restock)?;
+        write!(f, "\nsaferadius    :\t{}", self.saferadius)?;
+        write!(f, "\ndistanceradius:\t{}", self.distanceradius)?;
+        write!(f, "\ncleanupradius :\t{}", self.cleanupradius)?;
+
+        if let Some(ref secondary) = self.secondary {
+            write!(f, "\nsecondary     :\t{secondary}")?;
+        }
+
+        write!(f, "\nflags         :\t{}", self.flags)?;
+        write!(f, "\nposition      :\t{}", self.position)?;
+        write!(f, "\nlimit         :\t{}", self.limit)?;
+        write!(f, "\nactive        :\t{}", self.active)?;
+
+        for child in &self.children.children {
+            write!(f, "\nchild         :\t{child}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<raw::Event> for Event {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Event) -> Result<Self, Self::Error> {
+        let name = raw
+            .name
+            .ok_or_else(|| anyhow::Error::msg("event without name"))?;
+        let context = |error: anyhow::Error| anyhow::Error::msg(format!("event {name}: {error}"));
+        let number = |field: &str, text: Option<String>| {
+            parse_number(field, text.as_deref()).map_err(context)
+        };
+        Ok(Self {
+            nominal: number("nominal", raw.nominal)?,
+            min: number("min", raw.min)?,
+            max: number("max", raw.max)?,
+            lifetime: number("lifetime", raw.lifetime)?,
+            restock: number("restock", raw.restock)?,
+            saferadius: number("saferadius", raw.saferadius)?,
+            distanceradius: number("distanceradius", raw.distanceradius)?,
+            cleanupradius: number("cleanupradius", raw.cleanupradius)?,
+            secondary: raw.secondary,
+            flags: raw
+                .flags
+                .ok_or_else(|| anyhow::Error::msg("flags is missing"))
+                .and_then(Flags::try_from)
+                .map_err(context)?,
+            position: raw
+                .position
+                .ok_or_else(|| anyhow::Error::msg("position is missing"))
+                .and_then(|position| position.parse())
+                .map_err(context)?,
+            limit: raw
+                .limit
+                .ok_or_else(|| anyhow::Error::msg("limit is missing"))
+                .and_then(|limit| limit.parse())
+                .map_err(context)?,
+            active: parse_flag("active", raw.active.as_deref()).map_err(context)?,
+            children: Children {
+                children: raw
+                    .children
+                    .unwrap_or_default()
+                    .children
+                    .into_iter()
+                    .map(Child::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(context)?,
+            },
+            name,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+struct Children {
+    #[serde(rename = "child", default)]
+    children: Vec<Child>,
+}
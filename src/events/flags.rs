@@ -0,0 +1,77 @@
+use crate::events::{parse_flag, raw};
+use crate::util::{as_int, parse_bool_or_false};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// How the game treats the spawned items of an event
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "raw::Flags")]
+pub struct Flags {
+    #[serde(rename = "@deletable", serialize_with = "as_int")]
+    deletable: bool,
+    #[serde(rename = "@init_random", serialize_with = "as_int")]
+    init_random: bool,
+    #[serde(rename = "@remove_damaged", serialize_with = "as_int")]
+    remove_damaged: bool,
+}
+
+impl Flags {
+    #[must_use]
+    pub const fn get_deletable(&self) -> bool {
+        self.deletable
+    }
+
+    #[must_use]
+    pub const fn get_init_random(&self) -> bool {
+        self.init_random
+    }
+
+    #[must_use]
+    pub const fn get_remove_damaged(&self) -> bool {
+        self.remove_damaged
+    }
+
+    pub fn set_deletable(&mut self, deletable: bool) {
+        self.deletable = deletable;
+    }
+
+    pub fn set_init_random(&mut self, init_random: bool) {
+        self.init_random = init_random;
+    }
+
+    pub fn set_remove_damaged(&mut self, remove_damaged: bool) {
+        self.remove_damaged = remove_damaged;
+    }
+
+    /// Read the flags, treating invalid values as unset.
+    pub(crate) fn recover(raw: raw::Flags) -> Self {
+        let flag = |text: Option<String>| text.as_deref().is_some_and(parse_bool_or_false);
+        Self {
+            deletable: flag(raw.deletable),
+            init_random: flag(raw.init_random),
+            remove_damaged: flag(raw.remove_damaged),
+        }
+    }
+}
+
+impl Display for Flags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[ ")?;
+        write!(f, "deletable = {}, ", self.deletable)?;
+        write!(f, "init_random = {}, ", self.init_random)?;
+        write!(f, "remove_damaged = {}", self.remove_damaged)?;
+        write!(f, " ]")
+    }
+}
+
+impl TryFrom<raw::Flags> for Flags {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Flags) -> Result<Self, Self::Error> {
+        Ok(Self {
+            deletable: parse_flag("flags.deletable", raw.deletable.as_deref())?,
+            init_random: parse_flag("flags.init_random", raw.init_random.as_deref())?,
+            remove_damaged: parse_flag("flags.remove_damaged", raw.remove_damaged.as_deref())?,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Which amounts the nominal and min values of an event limit
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Limit {
+    /// The amounts of each child.
+    #[default]
+    Child,
+    /// The amounts of the children and of the event.
+    Mixed,
+    /// The amounts defined by the event's script.
+    Custom,
+    /// The amount of the event.
+    Parent,
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Child => write!(f, "child"),
+            Self::Mixed => write!(f, "mixed"),
+            Self::Custom => write!(f, "custom"),
+            Self::Parent => write!(f, "parent"),
+        }
+    }
+}
+
+impl Serialize for Limit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl FromStr for Limit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "child" => Ok(Self::Child),
+            "mixed" => Ok(Self::Mixed),
+            "custom" => Ok(Self::Custom),
+            "parent" => Ok(Self::Parent),
+            _ => Err(anyhow::Error::msg(format!("invalid limit: {s:?}"))),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Where an event spawns
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Position {
+    /// At the positions listed in `cfgeventspawns.xml`.
+    #[default]
+    Fixed,
+    /// Around the players.
+    Player,
+    /// Anywhere on the map.
+    Uniform,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed => write!(f, "fixed"),
+            Self::Player => write!(f, "player"),
+            Self::Uniform => write!(f, "uniform"),
+        }
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl FromStr for Position {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "fixed" => Ok(Self::Fixed),
+            "player" => Ok(Self::Player),
+            "uniform" => Ok(Self::Uniform),
+            _ => Err(anyhow::Error::msg(format!("invalid position: {s:?}"))),
+        }
+    }
+}
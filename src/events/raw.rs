@@ -0,0 +1,58 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename = "events")]
+pub struct Events {
+    #[serde(rename = "event", default)]
+    pub(crate) events: Vec<Event>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Event {
+    #[serde(rename = "@name")]
+    pub(crate) name: Option<String>,
+    pub(crate) nominal: Option<String>,
+    pub(crate) min: Option<String>,
+    pub(crate) max: Option<String>,
+    pub(crate) lifetime: Option<String>,
+    pub(crate) restock: Option<String>,
+    pub(crate) saferadius: Option<String>,
+    pub(crate) distanceradius: Option<String>,
+    pub(crate) cleanupradius: Option<String>,
+    pub(crate) secondary: Option<String>,
+    pub(crate) flags: Option<Flags>,
+    pub(crate) position: Option<String>,
+    pub(crate) limit: Option<String>,
+    pub(crate) active: Option<String>,
+    pub(crate) children: Option<Children>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct Flags {
+    #[serde(rename = "@deletable")]
+    pub(crate) deletable: Option<String>,
+    #[serde(rename = "@init_random")]
+    pub(crate) init_random: Option<String>,
+    #[serde(rename = "@remove_damaged")]
+    pub(crate) remove_damaged: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct Children {
+    #[serde(rename = "child", default)]
+    pub(crate) children: Vec<Child>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Child {
+    #[serde(rename = "@type")]
+    pub(crate) typ: Option<String>,
+    #[serde(rename = "@min")]
+    pub(crate) min: Option<String>,
+    #[serde(rename = "@max")]
+    pub(crate) max: Option<String>,
+    #[serde(rename = "@lootmin")]
+    pub(crate) lootmin: Option<String>,
+    #[serde(rename = "@lootmax")]
+    pub(crate) lootmax: Option<String>,
+}
@@ -0,0 +1,263 @@
+//! Parsing of `cfgeventspawns.xml` and checking it against `events.xml`.
+
+mod event_spawn;
+mod pos;
+mod raw;
+mod zone;
+
+use crate::document::{Document, Source};
+use crate::events::{Events, Position};
+use crate::validate::{Diagnostic, Rule};
+use serde::{Deserialize, Serialize};
+use serde_rw::{FromFile, ToXml};
+use std::fmt::{Display, Formatter};
+use std::slice::{Iter, IterMut};
+use std::str::FromStr;
+
+pub use event_spawn::EventSpawn;
+pub use pos::Pos;
+pub use zone::Zone;
+
+const DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#;
+
+/// The spawn positions of the events of a mission
+///
+/// If read from a well-formed document, that document is kept, so that comments,
+/// unknown elements and formatting survive writing the positions back.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "eventposdef")]
+pub struct EventSpawns {
+    #[serde(rename = "event", default)]
+    events: Vec<EventSpawn>,
+    #[serde(skip)]
+    source: Option<Source<EventSpawn>>,
+}
+
+impl EventSpawns {
+    /// Parse the cfgeventspawns.xml strictly
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file is not well-formed or an event cannot be deserialized.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        std::fs::read_to_string(filename)?.parse()
+    }
+
+    /// Parse the cfgeventspawns.xml gracefully
+    ///
+    /// Unknown elements, nameless events and positions without valid horizontal
+    /// coordinates are dropped.
+    /// If the file is not well-formed XML, its comments and formatting are not preserved.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` holding both the document and the deserialization error
+    /// if the deserialization fails.
+    pub fn read_gracefully(filename: &str) -> Result<Self, anyhow::Error> {
+        match Document::read(filename)
+            .and_then(|document| Source::recover(document, EventSpawn::recover_element))
+        {
+            Ok((source, events)) => Ok(Self {
+                events,
+                source: Some(source),
+            }),
+            Err(error) => raw::EventSpawns::from_file(filename)
+                .map(Self::from)
+                .map_err(|raw_error| anyhow::Error::msg(format!("{error}\n{raw_error}"))),
+        }
+    }
+
+    /// Write the cfgeventspawns.xml to the given file.
+    ///
+    /// Comments and formatting of the file the positions were read from are preserved.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to write.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if serialization or writing the file fails.
+    pub fn write(&self, filename: &str) -> Result<(), anyhow::Error> {
+        Ok(std::fs::write(filename, self.render()?)?)
+    }
+
+    pub fn events(&self) -> Iter<'_, EventSpawn> {
+        self.events.iter()
+    }
+
+    pub fn mut_events(&mut self) -> IterMut<'_, EventSpawn> {
+        self.events.iter_mut()
+    }
+
+    /// Return the positions of the event with the given name.
+    ///
+    /// Names are compared case-insensitively, like the game does.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&EventSpawn> {
+        self.events
+            .iter()
+            .find(|spawn| spawn.get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Return the positions of the event with the given name mutably.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut EventSpawn> {
+        self.events
+            .iter_mut()
+            .find(|spawn| spawn.get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Replace the positions of the event with the same name or add them if there are none.
+    ///
+    /// Returns the replaced positions.
+    pub fn insert(&mut self, spawn: EventSpawn) -> Option<EventSpawn> {
+        if let Some(existing) = self.get_mut(spawn.get_name()) {
+            Some(std::mem::replace(existing, spawn))
+        } else {
+            self.events.push(spawn);
+            None
+        }
+    }
+
+    /// Remove the positions of the event with the given name.
+    ///
+    /// Returns the removed positions.
+    pub fn remove(&mut self, name: &str) -> Option<EventSpawn> {
+        let position = self
+            .events
+            .iter()
+            .position(|spawn| spawn.get_name().eq_ignore_ascii_case(name))?;
+        Some(self.events.remove(position))
+    }
+
+    /// Check that every active event spawning at fixed positions has some
+    /// and that every event with positions is defined.
+    #[must_use]
+    pub fn validate(&self, events: &Events) -> Vec<Diagnostic> {
+        let missing = events
+            .events()
+            .filter(|event| event.get_active() && event.get_position() == Position::Fixed)
+            .filter(|event| {
+                self.get(event.get_name())
+                    .is_none_or(|spawn| spawn.get_positions().is_empty())
+            })
+            .map(|event| {
+                Diagnostic::new(
+                    Rule::EventWithoutSpawns,
+                    event.get_name(),
+                    "active event with fixed position has no spawn positions".to_string(),
+                )
+            });
+        let unknown = self
+            .events
+            .iter()
+            .filter(|spawn| !events.contains(spawn.get_name()))
+            .map(|spawn| {
+                Diagnostic::new(
+                    Rule::SpawnsWithoutEvent,
+                    spawn.get_name(),
+                    "has spawn positions but is not defined in events.xml".to_string(),
+                )
+            });
+        missing.chain(unknown).collect()
+    }
+
+    fn render(&self) -> Result<String, anyhow::Error> {
+        self.source.as_ref().map_or_else(
+            || Ok(format!("{DECLARATION}\n{}\n", self.to_xml_pretty(' ', 4)?)),
+            |source| Ok(source.render(&self.events)?.to_string()),
+        )
+    }
+}
+
+impl Display for EventSpawns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render().map_err(|_| std::fmt::Error)?)
+    }
+}
+
+impl FromStr for EventSpawns {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (source, events) = Source::parse(s.parse()?)?;
+        Ok(Self {
+            events,
+            source: Some(source),
+        })
+    }
+}
+
+impl PartialEq for EventSpawns {
+    fn eq(&self, other: &Self) -> bool {
+        self.events == other.events
+    }
+}
+
+impl From<raw::EventSpawns> for EventSpawns {
+    fn from(raw: raw::EventSpawns) -> Self {
+        Self {
+            events: raw
+                .events
+                .into_iter()
+                .filter_map(EventSpawn::recover)
+                .collect(),
+            source: None,
+        }
+    }
+}
+
+/// Parse a coordinate or other finite number.
+fn parse_coordinate(field: &str, text: Option<&str>) -> Result<f64, anyhow::Error> {
+    let text = text.ok_or_else(|| anyhow::Error::msg(format!("{field} is missing")))?;
+    text.parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .ok_or_else(|| anyhow::Error::msg(format!("{field} {text:?} is not a number")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventSpawn, EventSpawns};
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<eventposdef>
+    <!-- crashes -->
+    <event name="StaticHeliCrash">
+        <pos x="4212.5" z="10652.2" a="-12.5" />
+        <pos x="7580.33" y="340.1" z="5840.2" />
+    </event>
+    <event name="Loot_Bandit">
+        <zone smin="0" smax="0" dmin="3" dmax="5" r="45" />
+        <pos x="12071.933594" z="9130.875" a="-140.9" group="BanditCamp" />
+    </event>
+</eventposdef>
+"#;
+
+    #[test]
+    fn writes_unchanged_event_spawns_as_read() {
+        let spawns: EventSpawns = DOCUMENT.parse().unwrap();
+        assert_eq!(spawns.to_string(), DOCUMENT);
+    }
+
+    #[test]
+    fn writes_edits_into_the_original_elements() {
+        let mut spawns: EventSpawns = DOCUMENT.parse().unwrap();
+        spawns
+            .get_mut("Loot_Bandit")
+            .unwrap()
+            .mut_positions()
+            .iter_mut()
+            .for_each(|pos| pos.set_group(None));
+        spawns.insert(EventSpawn::new("VehicleCivilianSedan"));
+        assert_eq!(
+            spawns.to_string(),
+            DOCUMENT.replace(" group=\"BanditCamp\"", "").replace(
+                "</eventposdef>",
+                "    <event name=\"VehicleCivilianSedan\"/>\n</eventposdef>"
+            )
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::document::{Element, Entry};
+use crate::eventspawns::raw::{self, Child};
+use crate::eventspawns::{Pos, Zone};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The positions an event may spawn at
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "event", try_from = "raw::EventSpawn")]
+pub struct EventSpawn {
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone: Option<Zone>,
+    #[serde(rename = "pos")]
+    positions: Vec<Pos>,
+}
+
+impl EventSpawn {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            zone: None,
+            positions: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    #[must_use]
+    pub const fn get_zone(&self) -> Option<&Zone> {
+        self.zone.as_ref()
+    }
+
+    #[must_use]
+    pub fn get_positions(&self) -> &[Pos] {
+        &self.positions
+    }
+
+    #[must_use]
+    pub fn mut_positions(&mut self) -> &mut Vec<Pos> {
+        &mut self.positions
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn set_zone(&mut self, zone: Option<Zone>) {
+        self.zone = zone;
+    }
+
+    /// Read the event's positions, skipping unknown elements and invalid positions.
+    ///
+    /// Returns `None` if the event has no name.
+    pub(crate) fn recover(raw: raw::EventSpawn) -> Option<Self> {
+        let name = raw.name.filter(|name| !name.trim().is_empty())?;
+        let mut spawn = Self::new(name.trim());
+
+        for child in raw.children {
+            match child {
+                Child::Zone(zone) => spawn.zone = Some(Zone::recover(zone)),
+                Child::Pos(pos) => spawn.positions.extend(Pos::recover(pos)),
+                Child::Unknown => {}
+            }
+        }
+
+        Some(spawn)
+    }
+
+    /// Read the event's positions from its XML element gracefully, see [`EventSpawn::recover`].
+    pub(crate) fn recover_element(element: &Element) -> Result<Option<Self>, anyhow::Error> {
+        Ok(Self::recover(quick_xml::de::from_str(
+            &element.to_string(),
+        )?))
+    }
+}
+
+impl Entry for EventSpawn {
+    const ROOT: &'static str = "eventposdef";
+    const ELEMENT: &'static str = "event";
+
+    fn key(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn set_key(&mut self, key: &str) {
+        self.set_name(key);
+    }
+}
+
+impl Display for EventSpawn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "name:\t{}", self.name)?;
+
+        if let Some(ref zone) = self.zone {
+            write!(f, "\nzone:\t{zone}")?;
+        }
+
+        for pos in &self.positions {
+            write!(f, "\npos :\t{pos}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<raw::EventSpawn> for EventSpawn {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::EventSpawn) -> Result<Self, Self::Error> {
+        let name = raw
+            .name
+            .ok_or_else(|| anyhow::Error::msg("event without name"))?;
+        let mut spawn = Self::new(&name);
+        let context = |error: anyhow::Error| anyhow::Error::msg(format!("event {name}: {error}"));
+
+        for child in raw.children {
+            match child {
+                Child::Zone(zone) => {
+                    if spawn.zone.is_some() {
+                        return Err(context(anyhow::Error::msg("duplicate zone")));
+                    }
+
+                    spawn.zone = Some(Zone::try_from(zone).map_err(context)?);
+                }
+                Child::Pos(pos) => spawn.positions.push(Pos::try_from(pos).map_err(context)?),
+                Child::Unknown => return Err(context(anyhow::Error::msg("unknown element"))),
+            }
+        }
+
+        Ok(spawn)
+    }
+}
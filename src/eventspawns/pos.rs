@@ -0,0 +1,135 @@
+use crate::eventspawns::{parse_coordinate, raw};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A position an event may spawn at
+///
+/// Positions with a group spawn the objects of that group from `cfgeventgroups.xml`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "raw::Pos")]
+pub struct Pos {
+    #[serde(rename = "@x")]
+    x: f64,
+    #[serde(rename = "@y", skip_serializing_if = "Option::is_none")]
+    y: Option<f64>,
+    #[serde(rename = "@z")]
+    z: f64,
+    #[serde(rename = "@a", skip_serializing_if = "Option::is_none")]
+    a: Option<f64>,
+    #[serde(rename = "@group", skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+}
+
+impl Pos {
+    /// Create a position on the ground with the given horizontal coordinates.
+    #[must_use]
+    pub const fn new(x: f64, z: f64) -> Self {
+        Self {
+            x,
+            y: None,
+            z,
+            a: None,
+            group: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_x(&self) -> f64 {
+        self.x
+    }
+
+    #[must_use]
+    pub const fn get_y(&self) -> Option<f64> {
+        self.y
+    }
+
+    #[must_use]
+    pub const fn get_z(&self) -> f64 {
+        self.z
+    }
+
+    /// Return the angle in degrees.
+    #[must_use]
+    pub const fn get_a(&self) -> Option<f64> {
+        self.a
+    }
+
+    #[must_use]
+    pub fn get_group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    pub fn set_y(&mut self, y: Option<f64>) {
+        self.y = y;
+    }
+
+    pub fn set_a(&mut self, a: Option<f64>) {
+        self.a = a;
+    }
+
+    pub fn set_group(&mut self, group: Option<&str>) {
+        self.group = group.map(ToString::to_string);
+    }
+
+    /// Read the position, discarding invalid optional coordinates.
+    ///
+    /// Returns `None` if a horizontal coordinate is missing or invalid.
+    pub(crate) fn recover(raw: raw::Pos) -> Option<Self> {
+        let coordinate =
+            |text: Option<String>| text.and_then(|text| text.trim().parse::<f64>().ok());
+        Some(Self {
+            x: coordinate(raw.x)?,
+            y: coordinate(raw.y),
+            z: coordinate(raw.z)?,
+            a: coordinate(raw.a),
+            group: raw
+                .group
+                .map(|group| group.trim().to_string())
+                .filter(|group| !group.is_empty()),
+        })
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x = {}", self.x)?;
+
+        if let Some(y) = self.y {
+            write!(f, ", y = {y}")?;
+        }
+
+        write!(f, ", z = {}", self.z)?;
+
+        if let Some(a) = self.a {
+            write!(f, ", a = {a}")?;
+        }
+
+        if let Some(ref group) = self.group {
+            write!(f, ", group = {group}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<raw::Pos> for Pos {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Pos) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: parse_coordinate("x", raw.x.as_deref())?,
+            y: raw
+                .y
+                .as_deref()
+                .map(|y| parse_coordinate("y", Some(y)))
+                .transpose()?,
+            z: parse_coordinate("z", raw.z.as_deref())?,
+            a: raw
+                .a
+                .as_deref()
+                .map(|a| parse_coordinate("a", Some(a)))
+                .transpose()?,
+            group: raw.group,
+        })
+    }
+}
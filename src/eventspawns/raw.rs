@@ -0,0 +1,53 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename = "eventposdef")]
+pub struct EventSpawns {
+    #[serde(rename = "event", default)]
+    pub(crate) events: Vec<EventSpawn>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventSpawn {
+    #[serde(rename = "@name")]
+    pub(crate) name: Option<String>,
+    #[serde(rename = "$value", default)]
+    pub(crate) children: Vec<Child>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Child {
+    Zone(Zone),
+    Pos(Pos),
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Zone {
+    #[serde(rename = "@smin")]
+    pub(crate) smin: Option<String>,
+    #[serde(rename = "@smax")]
+    pub(crate) smax: Option<String>,
+    #[serde(rename = "@dmin")]
+    pub(crate) dmin: Option<String>,
+    #[serde(rename = "@dmax")]
+    pub(crate) dmax: Option<String>,
+    #[serde(rename = "@r")]
+    pub(crate) r: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Pos {
+    #[serde(rename = "@x")]
+    pub(crate) x: Option<String>,
+    #[serde(rename = "@y")]
+    pub(crate) y: Option<String>,
+    #[serde(rename = "@z")]
+    pub(crate) z: Option<String>,
+    #[serde(rename = "@a")]
+    pub(crate) a: Option<String>,
+    #[serde(rename = "@group")]
+    pub(crate) group: Option<String>,
+}
@@ -0,0 +1,97 @@
+use crate::eventspawns::{parse_coordinate, raw};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The area around each position in which an event's children spawn
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "raw::Zone")]
+pub struct Zone {
+    #[serde(rename = "@smin")]
+    smin: f64,
+    #[serde(rename = "@smax")]
+    smax: f64,
+    #[serde(rename = "@dmin")]
+    dmin: f64,
+    #[serde(rename = "@dmax")]
+    dmax: f64,
+    #[serde(rename = "@r")]
+    r: f64,
+}
+
+impl Zone {
+    #[must_use]
+    pub const fn new(smin: f64, smax: f64, dmin: f64, dmax: f64, r: f64) -> Self {
+        Self {
+            smin,
+            smax,
+            dmin,
+            dmax,
+            r,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_smin(&self) -> f64 {
+        self.smin
+    }
+
+    #[must_use]
+    pub const fn get_smax(&self) -> f64 {
+        self.smax
+    }
+
+    #[must_use]
+    pub const fn get_dmin(&self) -> f64 {
+        self.dmin
+    }
+
+    #[must_use]
+    pub const fn get_dmax(&self) -> f64 {
+        self.dmax
+    }
+
+    /// Return the radius in meters.
+    #[must_use]
+    pub const fn get_r(&self) -> f64 {
+        self.r
+    }
+
+    /// Read the zone, replacing missing or invalid values with 0.
+    pub(crate) fn recover(raw: raw::Zone) -> Self {
+        let value = |text: Option<String>| {
+            text.and_then(|text| text.trim().parse().ok())
+                .unwrap_or_default()
+        };
+        Self::new(
+            value(raw.smin),
+            value(raw.smax),
+            value(raw.dmin),
+            value(raw.dmax),
+            value(raw.r),
+        )
+    }
+}
+
+impl Display for Zone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "smin = {}, smax = {}, dmin = {}, dmax = {}, r = {}",
+            self.smin, self.smax, self.dmin, self.dmax, self.r
+        )
+    }
+}
+
+impl TryFrom<raw::Zone> for Zone {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Zone) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            parse_coordinate("zone smin", raw.smin.as_deref())?,
+            parse_coordinate("zone smax", raw.smax.as_deref())?,
+            parse_coordinate("zone dmin", raw.dmin.as_deref())?,
+            parse_coordinate("zone dmax", raw.dmax.as_deref())?,
+            parse_coordinate("zone r", raw.r.as_deref())?,
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+//! Filter expressions over type fields.
+//!
+//! An expression consists of conditions on the fields of a type:
+//!
+//! * numeric fields compare to numbers, e.g. `nominal > 5`
+//! * `name` and `category` compare case-insensitively to names, e.g. `category == weapons`
+//! * flags are used as booleans or compared to them, e.g. `crafted` or `deloot == false`
+//! * `tag`, `usage` and `value` are checked for names, e.g. `usage contains Military`,
+//!   where `user:NAME` refers to a group of `cfglimitsdefinitionuser.xml`
+//!
+//! Conditions can be negated with `!`, grouped with parentheses and combined
+//! with `&&` and `||`, where `&&` binds stronger. Unset fields never match.
+
+mod comparison;
+mod expression;
+mod field;
+mod parser;
+mod token;
+
+use crate::Type;
+use expression::Expression;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub use comparison::Comparison;
+pub use field::{Field, Kind};
+
+/// A parsed filter expression
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Filter {
+    text: String,
+    expression: Expression,
+}
+
+impl Filter {
+    /// Check whether the given type matches this filter.
+    #[must_use]
+    pub fn matches(&self, typ: &Type) -> bool {
+        self.expression.matches(typ)
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            text: text.to_string(),
+            expression: parser::parse(text)?,
+        })
+    }
+}
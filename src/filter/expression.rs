@@ -0,0 +1,37 @@
+use crate::filter::{Comparison, Field};
+use crate::{Named, Type};
+
+/// A node of the filter's syntax tree
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expression {
+    Number(Field, Comparison, i64),
+    Text(Field, Comparison, String),
+    Flag(Field, bool),
+    Contains(Field, Named),
+    Not(Box<Self>),
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+}
+
+impl Expression {
+    pub fn matches(&self, typ: &Type) -> bool {
+        match self {
+            Self::Number(field, comparison, number) => field
+                .number(typ)
+                .is_some_and(|value| comparison.holds(value.cmp(number))),
+            Self::Text(field, comparison, text) => field.text(typ).is_some_and(|value| {
+                comparison.holds(value.to_ascii_lowercase().cmp(&text.to_ascii_lowercase()))
+            }),
+            Self::Flag(field, expected) => field.flag(typ) == Some(*expected),
+            Self::Contains(field, name) => field.list(typ).is_some_and(|names| {
+                names.iter().any(|item| {
+                    item.is_user() == name.is_user()
+                        && item.name().eq_ignore_ascii_case(name.name())
+                })
+            }),
+            Self::Not(expression) => !expression.matches(typ),
+            Self::And(lhs, rhs) => lhs.matches(typ) && rhs.matches(typ),
+            Self::Or(lhs, rhs) => lhs.matches(typ) || rhs.matches(typ),
+        }
+    }
+}
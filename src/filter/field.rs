@@ -0,0 +1,163 @@
+use crate::{Named, Type};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A type field that can be filtered on
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Field {
+    Name,
+    Nominal,
+    Lifetime,
+    Restock,
+    Min,
+    Quantmin,
+    Quantmax,
+    Cost,
+    CountInCargo,
+    CountInHoarder,
+    CountInMap,
+    CountInPlayer,
+    Crafted,
+    DeLoot,
+    Category,
+    Tag,
+    Usage,
+    Value,
+}
+
+/// The kind of values a field holds
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Kind {
+    Number,
+    Text,
+    Flag,
+    List,
+}
+
+impl Field {
+    #[must_use]
+    pub const fn kind(self) -> Kind {
+        match self {
+            Self::Name | Self::Category => Kind::Text,
+            Self::Nominal
+            | Self::Lifetime
+            | Self::Restock
+            | Self::Min
+            | Self::Quantmin
+            | Self::Quantmax
+            | Self::Cost => Kind::Number,
+            Self::CountInCargo
+            | Self::CountInHoarder
+            | Self::CountInMap
+            | Self::CountInPlayer
+            | Self::Crafted
+            | Self::DeLoot => Kind::Flag,
+            Self::Tag | Self::Usage | Self::Value => Kind::List,
+        }
+    }
+
+    /// Return the field's numeric value, if it is a numeric field and set.
+    #[must_use]
+    pub fn number(self, typ: &Type) -> Option<i64> {
+        match self {
+            Self::Nominal => typ.get_nominal().map(i64::from),
+            Self::Lifetime => Some(typ.get_lifetime().into()),
+            Self::Restock => typ.get_restock().map(i64::from),
+            Self::Min => Some(typ.get_min().into()),
+            Self::Quantmin => typ.get_quantmin(),
+            Self::Quantmax => Some(typ.get_quantmax()),
+            Self::Cost => typ.get_cost().map(i64::from),
+            _ => None,
+        }
+    }
+
+    /// Return the field's textual value, if it is a textual field and set.
+    #[must_use]
+    pub fn text(self, typ: &Type) -> Option<&str> {
+        match self {
+            Self::Name => Some(typ.get_name()),
+            Self::Category => typ.get_category().map(Named::name),
+            _ => None,
+        }
+    }
+
+    /// Return the flag's value, if it is a flag.
+    #[must_use]
+    pub const fn flag(self, typ: &Type) -> Option<bool> {
+        let flags = typ.get_flags();
+
+        match self {
+            Self::CountInCargo => Some(flags.get_count_in_cargo()),
+            Self::CountInHoarder => Some(flags.get_count_in_hoarder()),
+            Self::CountInMap => Some(flags.get_count_in_map()),
+            Self::CountInPlayer => Some(flags.get_count_in_player()),
+            Self::Crafted => Some(flags.get_crafted()),
+            Self::DeLoot => Some(flags.get_deloot()),
+            _ => None,
+        }
+    }
+
+    /// Return the field's names, if it is a list field and set.
+    #[must_use]
+    pub fn list(self, typ: &Type) -> Option<&[Named]> {
+        match self {
+            Self::Tag => typ.get_tags(),
+            Self::Usage => typ.get_usages(),
+            Self::Value => typ.get_values(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name => write!(f, "name"),
+            Self::Nominal => write!(f, "nominal"),
+            Self::Lifetime => write!(f, "lifetime"),
+            Self::Restock => write!(f, "restock"),
+            Self::Min => write!(f, "min"),
+            Self::Quantmin => write!(f, "quantmin"),
+            Self::Quantmax => write!(f, "quantmax"),
+            Self::Cost => write!(f, "cost"),
+            Self::CountInCargo => write!(f, "count_in_cargo"),
+            Self::CountInHoarder => write!(f, "count_in_hoarder"),
+            Self::CountInMap => write!(f, "count_in_map"),
+            Self::CountInPlayer => write!(f, "count_in_player"),
+            Self::Crafted => write!(f, "crafted"),
+            Self::DeLoot => write!(f, "deloot"),
+            Self::Category => write!(f, "category"),
+            Self::Tag => write!(f, "tag"),
+            Self::Usage => write!(f, "usage"),
+            Self::Value => write!(f, "value"),
+        }
+    }
+}
+
+impl FromStr for Field {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "nominal" => Ok(Self::Nominal),
+            "lifetime" => Ok(Self::Lifetime),
+            "restock" => Ok(Self::Restock),
+            "min" => Ok(Self::Min),
+            "quantmin" => Ok(Self::Quantmin),
+            "quantmax" => Ok(Self::Quantmax),
+            "cost" => Ok(Self::Cost),
+            "count_in_cargo" => Ok(Self::CountInCargo),
+            "count_in_hoarder" => Ok(Self::CountInHoarder),
+            "count_in_map" => Ok(Self::CountInMap),
+            "count_in_player" => Ok(Self::CountInPlayer),
+            "crafted" => Ok(Self::Crafted),
+            "deloot" => Ok(Self::DeLoot),
+            "category" => Ok(Self::Category),
+            "tag" | "tags" => Ok(Self::Tag),
+            "usage" | "usages" => Ok(Self::Usage),
+            "value" | "values" => Ok(Self::Value),
+            _ => Err(anyhow::Error::msg(format!("unknown field: {name}"))),
+        }
+    }
+}
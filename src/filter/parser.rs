@@ -0,0 +1,227 @@
+use crate::filter::expression::Expression;
+use crate::filter::token::{tokenize, Token};
+use crate::filter::{Comparison, Field, Kind};
+use crate::Named;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+type Tokens = Peekable<IntoIter<Token>>;
+
+/// Parse a filter expression.
+pub fn parse(text: &str) -> Result<Expression, anyhow::Error> {
+    let mut tokens = tokenize(text)?.into_iter().peekable();
+    let expression = parse_or(&mut tokens)?;
+
+    if let Some(token) = tokens.next() {
+        return Err(anyhow::Error::msg(format!("unexpected token: {token}")));
+    }
+
+    Ok(expression)
+}
+
+fn parse_or(tokens: &mut Tokens) -> Result<Expression, anyhow::Error> {
+    let mut expression = parse_and(tokens)?;
+
+    while tokens.next_if_eq(&Token::Or).is_some() {
+        expression = Expression::Or(Box::new(expression), Box::new(parse_and(tokens)?));
+    }
+
+    Ok(expression)
+}
+
+fn parse_and(tokens: &mut Tokens) -> Result<Expression, anyhow::Error> {
+    let mut expression = parse_unary(tokens)?;
+
+    while tokens.next_if_eq(&Token::And).is_some() {
+        expression = Expression::And(Box::new(expression), Box::new(parse_unary(tokens)?));
+    }
+
+    Ok(expression)
+}
+
+fn parse_unary(tokens: &mut Tokens) -> Result<Expression, anyhow::Error> {
+    match tokens.peek() {
+        Some(Token::Not) => {
+            tokens.next();
+            Ok(Expression::Not(Box::new(parse_unary(tokens)?)))
+        }
+        Some(Token::Open) => {
+            tokens.next();
+            let expression = parse_or(tokens)?;
+
+            match tokens.next() {
+                Some(Token::Close) => Ok(expression),
+                other => Err(unexpected(other, "')'")),
+            }
+        }
+        _ => parse_condition(tokens),
+    }
+}
+
+fn parse_condition(tokens: &mut Tokens) -> Result<Expression, anyhow::Error> {
+    let field: Field = match tokens.next() {
+        Some(Token::Word(word)) => word.parse()?,
+        other => return Err(unexpected(other, "field name")),
+    };
+
+    match field.kind() {
+        Kind::Number => {
+            let comparison = parse_comparison(tokens)?;
+
+            match tokens.next() {
+                Some(Token::Number(number)) => Ok(Expression::Number(field, comparison, number)),
+                other => Err(unexpected(other, "number")),
+            }
+        }
+        Kind::Text => {
+            let comparison = parse_comparison(tokens)?;
+
+            match tokens.next() {
+                Some(Token::Word(word)) => Ok(Expression::Text(field, comparison, word)),
+                Some(Token::Number(number)) => {
+                    Ok(Expression::Text(field, comparison, number.to_string()))
+                }
+                other => Err(unexpected(other, "name")),
+            }
+        }
+        Kind::Flag => parse_flag(field, tokens),
+        Kind::List => {
+            match tokens.next() {
+                Some(Token::Word(word)) if word.eq_ignore_ascii_case("contains") => {}
+                other => return Err(unexpected(other, "'contains'")),
+            }
+
+            match tokens.next() {
+                Some(Token::Word(word)) => Ok(Expression::Contains(field, Named::from_text(&word))),
+                Some(Token::Number(number)) => {
+                    Ok(Expression::Contains(field, Named::new(number.to_string())))
+                }
+                other => Err(unexpected(other, "name")),
+            }
+        }
+    }
+}
+
+/// Parse a flag, which is either used as a boolean or compared to one.
+fn parse_flag(field: Field, tokens: &mut Tokens) -> Result<Expression, anyhow::Error> {
+    let negate = match tokens.peek() {
+        Some(Token::Comparison(Comparison::Equal)) => false,
+        Some(Token::Comparison(Comparison::NotEqual)) => true,
+        _ => return Ok(Expression::Flag(field, true)),
+    };
+    tokens.next();
+    let value = match tokens.next() {
+        Some(Token::Number(0)) => false,
+        Some(Token::Number(1)) => true,
+        Some(Token::Word(word)) if word.eq_ignore_ascii_case("false") => false,
+        Some(Token::Word(word)) if word.eq_ignore_ascii_case("true") => true,
+        other => return Err(unexpected(other, "boolean")),
+    };
+    Ok(Expression::Flag(field, value != negate))
+}
+
+fn parse_comparison(tokens: &mut Tokens) -> Result<Comparison, anyhow::Error> {
+    match tokens.next() {
+        Some(Token::Comparison(comparison)) => Ok(comparison),
+        other => Err(unexpected(other, "comparison operator")),
+    }
+}
+
+fn unexpected(token: Option<Token>, expected: &str) -> anyhow::Error {
+    token.map_or_else(
+        || anyhow::Error::msg(format!("expected {expected}, found end of expression")),
+        |token| anyhow::Error::msg(format!("expected {expected}, found {token}")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::filter::expression::Expression;
+    use crate::filter::{Comparison, Field};
+    use crate::Named;
+
+    #[test]
+    fn parses_conditions() {
+        assert_eq!(
+            parse("nominal >= 5").unwrap(),
+            Expression::Number(Field::Nominal, Comparison::GreaterOrEqual, 5)
+        );
+        assert_eq!(
+            parse("category == weapons").unwrap(),
+            Expression::Text(Field::Category, Comparison::Equal, "weapons".to_string())
+        );
+        assert_eq!(
+            parse("usage contains Military").unwrap(),
+            Expression::Contains(Field::Usage, Named::new("Military".to_string()))
+        );
+        assert_eq!(
+            parse("usage contains user:Coast").unwrap(),
+            Expression::Contains(Field::Usage, Named::user("Coast".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_flags() {
+        assert_eq!(
+            parse("crafted").unwrap(),
+            Expression::Flag(Field::Crafted, true)
+        );
+        assert_eq!(
+            parse("deloot == false").unwrap(),
+            Expression::Flag(Field::DeLoot, false)
+        );
+        assert_eq!(
+            parse("deloot != 1").unwrap(),
+            Expression::Flag(Field::DeLoot, false)
+        );
+    }
+
+    #[test]
+    fn and_binds_stronger_than_or() {
+        assert_eq!(
+            parse("crafted || deloot && !count_in_map").unwrap(),
+            Expression::Or(
+                Box::new(Expression::Flag(Field::Crafted, true)),
+                Box::new(Expression::And(
+                    Box::new(Expression::Flag(Field::DeLoot, true)),
+                    Box::new(Expression::Not(Box::new(Expression::Flag(
+                        Field::CountInMap,
+                        true
+                    )))),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn parentheses_group() {
+        assert_eq!(
+            parse("(crafted || deloot) && min > 0").unwrap(),
+            Expression::And(
+                Box::new(Expression::Or(
+                    Box::new(Expression::Flag(Field::Crafted, true)),
+                    Box::new(Expression::Flag(Field::DeLoot, true)),
+                )),
+                Box::new(Expression::Number(Field::Min, Comparison::Greater, 0)),
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        for text in [
+            "",
+            "nominal",
+            "nominal > ",
+            "nominal > five",
+            "(crafted",
+            "crafted)",
+            "usage == Military",
+            "unknown == 1",
+            "crafted == maybe",
+        ] {
+            assert!(parse(text).is_err(), "{text:?} should not parse");
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::filter::Comparison;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Token {
+    Word(String),
+    Number(i64),
+    Comparison(Comparison),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+/// Split the expression into tokens.
+pub fn tokenize(text: &str) -> Result<Vec<Token>, anyhow::Error> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(&char) = chars.peek() {
+        match char {
+            _ if char.is_whitespace() => {
+                chars.next();
+            }
+            '&' | '|' => {
+                chars.next();
+
+                if chars.next() != Some(char) {
+                    return Err(anyhow::Error::msg(format!("expected '{char}{char}'")));
+                }
+
+                tokens.push(if char == '&' { Token::And } else { Token::Or });
+            }
+            '(' | ')' => {
+                chars.next();
+                tokens.push(if char == '(' {
+                    Token::Open
+                } else {
+                    Token::Close
+                });
+            }
+            '!' => {
+                chars.next();
+                tokens.push(if chars.next_if_eq(&'=').is_some() {
+                    Token::Comparison(Comparison::NotEqual)
+                } else {
+                    Token::Not
+                });
+            }
+            '=' | '<' | '>' => {
+                chars.next();
+                let equals = chars.next_if_eq(&'=').is_some();
+                tokens.push(Token::Comparison(match (char, equals) {
+                    ('=', true) => Comparison::Equal,
+                    ('<', false) => Comparison::Less,
+                    ('<', true) => Comparison::LessOrEqual,
+                    ('>', false) => Comparison::Greater,
+                    ('>', true) => Comparison::GreaterOrEqual,
+                    _ => return Err(anyhow::Error::msg(format!("unexpected '{char}'"))),
+                }));
+            }
+            '"' | '\'' => {
+                chars.next();
+                let mut word = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some(next) if next == char => break,
+                        Some(next) => word.push(next),
+                        None => {
+                            return Err(anyhow::Error::msg(format!(
+                                "unterminated string: {char}{word}"
+                            )))
+                        }
+                    }
+                }
+
+                tokens.push(Token::Word(word));
+            }
+            _ => {
+                let mut word = String::new();
+
+                while let Some(next) = chars.next_if(|&next| is_word_char(next)) {
+                    word.push(next);
+                }
+
+                if word.is_empty() {
+                    return Err(anyhow::Error::msg(format!("unexpected '{char}'")));
+                }
+
+                tokens.push(
+                    word.parse::<i64>()
+                        .map_or_else(|_| Token::Word(word), Token::Number),
+                );
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_word_char(char: char) -> bool {
+    char.is_alphanumeric() || matches!(char, '_' | '-' | '.' | ':')
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Word(word) => write!(f, "'{word}'"),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Comparison(comparison) => write!(f, "'{comparison}'"),
+            Self::And => write!(f, "'&&'"),
+            Self::Or => write!(f, "'||'"),
+            Self::Not => write!(f, "'!'"),
+            Self::Open => write!(f, "'('"),
+            Self::Close => write!(f, "')'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Token};
+    use crate::filter::Comparison;
+
+    #[test]
+    fn tokenizes_operators() {
+        assert_eq!(
+            tokenize("!(a<=1)||b!=2&&c>3").unwrap(),
+            vec![
+                Token::Not,
+                Token::Open,
+                Token::Word("a".to_string()),
+                Token::Comparison(Comparison::LessOrEqual),
+                Token::Number(1),
+                Token::Close,
+                Token::Or,
+                Token::Word("b".to_string()),
+                Token::Comparison(Comparison::NotEqual),
+                Token::Number(2),
+                Token::And,
+                Token::Word("c".to_string()),
+                Token::Comparison(Comparison::Greater),
+                Token::Number(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_numbers_and_words() {
+        assert_eq!(
+            tokenize("-1 Ammo_9x19 tier1.5").unwrap(),
+            vec![
+                Token::Number(-1),
+                Token::Word("Ammo_9x19".to_string()),
+                Token::Word("tier1.5".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_quoted_strings() {
+        assert_eq!(
+            tokenize(r#"name == "a b" || name == 'c"d'"#).unwrap(),
+            vec![
+                Token::Word("name".to_string()),
+                Token::Comparison(Comparison::Equal),
+                Token::Word("a b".to_string()),
+                Token::Or,
+                Token::Word("name".to_string()),
+                Token::Comparison(Comparison::Equal),
+                Token::Word("c\"d".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unterminated_strings() {
+        assert!(tokenize(r#"category == "weap"#).is_err());
+        assert!(tokenize("category == 'weap").is_err());
+    }
+
+    #[test]
+    fn rejects_single_operators() {
+        assert!(tokenize("a & b").is_err());
+        assert!(tokenize("a | b").is_err());
+        assert!(tokenize("a = 1").is_err());
+        assert!(tokenize("a # 1").is_err());
+    }
+}
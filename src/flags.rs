@@ -1,5 +1,6 @@
-use crate::raw;
-use crate::util::{as_int, parse_bool_or_false};
+use crate::diff::Change;
+use crate::merge::Merger;
+use crate::util::as_int;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -21,6 +22,36 @@ pub struct Flags {
 }
 
 impl Flags {
+    #[must_use]
+    pub const fn get_count_in_cargo(&self) -> bool {
+        self.count_in_cargo
+    }
+
+    #[must_use]
+    pub const fn get_count_in_hoarder(&self) -> bool {
+        self.count_in_hoarder
+    }
+
+    #[must_use]
+    pub const fn get_count_in_map(&self) -> bool {
+        self.count_in_map
+    }
+
+    #[must_use]
+    pub const fn get_count_in_player(&self) -> bool {
+        self.count_in_player
+    }
+
+    #[must_use]
+    pub const fn get_crafted(&self) -> bool {
+        self.crafted
+    }
+
+    #[must_use]
+    pub const fn get_deloot(&self) -> bool {
+        self.deloot
+    }
+
     pub fn set_count_in_cargo(&mut self, count_in_cargo: bool) {
         self.count_in_cargo = count_in_cargo;
     }
@@ -44,6 +75,73 @@ impl Flags {
     pub fn set_deloot(&mut self, deloot: bool) {
         self.deloot = deloot;
     }
+
+    /// Merge their changes to the common `base` into ours flag by flag.
+    pub(crate) fn merge3(&self, base: Option<&Self>, theirs: &Self, merger: &mut Merger) -> Self {
+        Self {
+            count_in_cargo: merger.merge(
+                "flags.count_in_cargo",
+                base.map(|base| &base.count_in_cargo),
+                &self.count_in_cargo,
+                &theirs.count_in_cargo,
+            ),
+            count_in_hoarder: merger.merge(
+                "flags.count_in_hoarder",
+                base.map(|base| &base.count_in_hoarder),
+                &self.count_in_hoarder,
+                &theirs.count_in_hoarder,
+            ),
+            count_in_map: merger.merge(
+                "flags.count_in_map",
+                base.map(|base| &base.count_in_map),
+                &self.count_in_map,
+                &theirs.count_in_map,
+            ),
+            count_in_player: merger.merge(
+                "flags.count_in_player",
+                base.map(|base| &base.count_in_player),
+                &self.count_in_player,
+                &theirs.count_in_player,
+            ),
+            crafted: merger.merge(
+                "flags.crafted",
+                base.map(|base| &base.crafted),
+                &self.crafted,
+                &theirs.crafted,
+            ),
+            deloot: merger.merge(
+                "flags.deloot",
+                base.map(|base| &base.deloot),
+                &self.deloot,
+                &theirs.deloot,
+            ),
+        }
+    }
+
+    /// Return the changes of all flags from `self` to `other`.
+    pub(crate) fn diff(&self, other: &Self) -> Vec<Change> {
+        [
+            ("count_in_cargo", self.count_in_cargo, other.count_in_cargo),
+            (
+                "count_in_hoarder",
+                self.count_in_hoarder,
+                other.count_in_hoarder,
+            ),
+            ("count_in_map", self.count_in_map, other.count_in_map),
+            (
+                "count_in_player",
+                self.count_in_player,
+                other.count_in_player,
+            ),
+            ("crafted", self.crafted, other.crafted),
+            ("deloot", self.deloot, other.deloot),
+        ]
+        .into_iter()
+        .filter_map(|(name, old, new)| {
+            Change::field(&format!("flags.{name}"), Some(old), Some(new))
+        })
+        .collect()
+    }
 }
 
 impl Display for Flags {
@@ -58,22 +156,3 @@ impl Display for Flags {
         write!(f, " ]")
     }
 }
-
-impl From<raw::Flags> for Flags {
-    fn from(raw: raw::Flags) -> Self {
-        Self {
-            count_in_cargo: raw
-                .count_in_cargo
-                .map_or(false, |s| parse_bool_or_false(&s)),
-            count_in_hoarder: raw
-                .count_in_hoarder
-                .map_or(false, |s| parse_bool_or_false(&s)),
-            count_in_map: raw.count_in_map.map_or(false, |s| parse_bool_or_false(&s)),
-            count_in_player: raw
-                .count_in_player
-                .map_or(false, |s| parse_bool_or_false(&s)),
-            crafted: raw.crafted.map_or(false, |s| parse_bool_or_false(&s)),
-            deloot: raw.deloot.map_or(false, |s| parse_bool_or_false(&s)),
-        }
-    }
-}
@@ -0,0 +1,177 @@
+//! Serialization of types to and from formats other than XML.
+//!
+//! The XML representation relies on attribute and element renames, which lead to
+//! awkward output in other formats. The formats in this module instead use a stable
+//! representation with plain field names, where tags, usages and values are arrays of names.
+//!
+//! JSON, YAML and TOML are available behind the `json`, `yaml` and `toml` cargo features.
+
+#[cfg(any(feature = "json", feature = "yaml", feature = "toml"))]
+mod records;
+
+use crate::{Type, Types};
+#[cfg(any(feature = "json", feature = "yaml", feature = "toml"))]
+use records::{TypeRecord, TypesRecord};
+use serde_rw::ToXml;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Format {
+    #[default]
+    Xml,
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl Format {
+    /// Determine the format from a file's extension.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file.
+    #[must_use]
+    pub fn from_path(filename: &str) -> Option<Self> {
+        Path::new(filename)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| extension.parse().ok())
+    }
+
+    /// Serialize the types.
+    ///
+    /// # Arguments
+    /// * `types` - The types to serialize.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the serialization fails.
+    pub fn serialize_types(self, types: &Types) -> Result<String, anyhow::Error> {
+        match self {
+            Self::Xml => Ok(types.to_string()),
+            #[cfg(feature = "json")]
+            Self::Json => Ok(serde_json::to_string_pretty(&TypesRecord::from(types))?),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => Ok(serde_yaml::to_string(&TypesRecord::from(types))?),
+            #[cfg(feature = "toml")]
+            Self::Toml => Ok(toml::to_string_pretty(&TypesRecord::from(types))?),
+        }
+    }
+
+    /// Serialize a single type.
+    ///
+    /// # Arguments
+    /// * `typ` - The type to serialize.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the serialization fails.
+    pub fn serialize_type(self, typ: &Type) -> Result<String, anyhow::Error> {
+        match self {
+            Self::Xml => typ.to_xml_pretty(' ', 4),
+            #[cfg(feature = "json")]
+            Self::Json => Ok(serde_json::to_string_pretty(&TypeRecord::from(typ))?),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => Ok(serde_yaml::to_string(&TypeRecord::from(typ))?),
+            #[cfg(feature = "toml")]
+            Self::Toml => Ok(toml::to_string_pretty(&TypeRecord::from(typ))?),
+        }
+    }
+
+    /// Deserialize types.
+    ///
+    /// # Arguments
+    /// * `text` - The serialized types.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the deserialization fails.
+    pub fn deserialize_types(self, text: &str) -> Result<Types, anyhow::Error> {
+        match self {
+            Self::Xml => text.parse(),
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::from_str::<TypesRecord>(text)?.try_into(),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => serde_yaml::from_str::<TypesRecord>(text)?.try_into(),
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::from_str::<TypesRecord>(text)?.try_into(),
+        }
+    }
+
+    /// Deserialize a single type, as serialized by [`Format::serialize_type`].
+    ///
+    /// # Arguments
+    /// * `text` - The serialized type.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the deserialization fails.
+    pub fn deserialize_type(self, text: &str) -> Result<Type, anyhow::Error> {
+        match self {
+            Self::Xml => Type::from_xml(text),
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::from_str::<TypeRecord>(text)?.try_into(),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => serde_yaml::from_str::<TypeRecord>(text)?.try_into(),
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::from_str::<TypeRecord>(text)?.try_into(),
+        }
+    }
+
+    /// Read a single type from the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file cannot be read or deserialized.
+    pub fn read_type(self, filename: &str) -> Result<Type, anyhow::Error> {
+        self.deserialize_type(&std::fs::read_to_string(filename)?)
+    }
+
+    /// Read types from the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file cannot be read or deserialized.
+    pub fn read_types(self, filename: &str) -> Result<Types, anyhow::Error> {
+        if self == Self::Xml {
+            Types::read(filename)
+        } else {
+            self.deserialize_types(&std::fs::read_to_string(filename)?)
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Xml => write!(f, "xml"),
+            #[cfg(feature = "json")]
+            Self::Json => write!(f, "json"),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => write!(f, "yaml"),
+            #[cfg(feature = "toml")]
+            Self::Toml => write!(f, "toml"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "xml" => Ok(Self::Xml),
+            #[cfg(feature = "json")]
+            "json" => Ok(Self::Json),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Ok(Self::Yaml),
+            #[cfg(feature = "toml")]
+            "toml" => Ok(Self::Toml),
+            other => Err(anyhow::Error::msg(format!("Unsupported format: {other}"))),
+        }
+    }
+}
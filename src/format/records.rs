@@ -0,0 +1,172 @@
+use crate::{Flags, Named, Type, Types};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TypesRecord {
+    #[serde(default)]
+    types: Vec<TypeRecord>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TypeRecord {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nominal: Option<u32>,
+    lifetime: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    restock: Option<u32>,
+    min: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    quantmin: Option<i64>,
+    quantmax: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cost: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    usages: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    values: Option<Vec<String>>,
+    #[serde(default)]
+    flags: FlagsRecord,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct FlagsRecord {
+    count_in_cargo: bool,
+    count_in_hoarder: bool,
+    count_in_map: bool,
+    count_in_player: bool,
+    crafted: bool,
+    deloot: bool,
+}
+
+impl From<&Types> for TypesRecord {
+    fn from(types: &Types) -> Self {
+        Self {
+            types: types.types().map(TypeRecord::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<TypesRecord> for Types {
+    type Error = anyhow::Error;
+
+    /// Duplicate types are kept, as when reading XML, so that converting round-trips them.
+    fn try_from(record: TypesRecord) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            record
+                .types
+                .into_iter()
+                .map(Type::try_from)
+                .collect::<Result<_, _>>()?,
+            None,
+        ))
+    }
+}
+
+impl From<&Type> for TypeRecord {
+    fn from(typ: &Type) -> Self {
+        Self {
+            name: typ.get_name().to_string(),
+            nominal: typ.get_nominal(),
+            lifetime: typ.get_lifetime(),
+            restock: typ.get_restock(),
+            min: typ.get_min(),
+            quantmin: typ.get_quantmin(),
+            quantmax: typ.get_quantmax(),
+            cost: typ.get_cost(),
+            category: typ.get_category().map(ToString::to_string),
+            tags: names(typ.get_tags()),
+            usages: names(typ.get_usages()),
+            values: names(typ.get_values()),
+            flags: typ.get_flags().into(),
+        }
+    }
+}
+
+impl TryFrom<TypeRecord> for Type {
+    type Error = anyhow::Error;
+
+    fn try_from(record: TypeRecord) -> Result<Self, Self::Error> {
+        if record.name.is_empty() {
+            return Err(anyhow::Error::msg("Type without name"));
+        }
+
+        let mut typ = Self::new(&record.name);
+        typ.set_nominal(record.nominal);
+        typ.set_lifetime(record.lifetime);
+        typ.set_restock(record.restock);
+        typ.set_min(record.min);
+        typ.set_quantmin(record.quantmin);
+        typ.set_quantmax(record.quantmax);
+        typ.set_cost(record.cost);
+        typ.set_category(record.category.as_deref().map(Named::from_text).as_ref());
+        typ.set_tags(named(record.tags).as_deref());
+        typ.set_usages(named(record.usages).as_deref());
+        typ.set_values(named(record.values).as_deref());
+        typ.set_flags(record.flags.into());
+        Ok(typ)
+    }
+}
+
+impl From<&Flags> for FlagsRecord {
+    fn from(flags: &Flags) -> Self {
+        Self {
+            count_in_cargo: flags.get_count_in_cargo(),
+            count_in_hoarder: flags.get_count_in_hoarder(),
+            count_in_map: flags.get_count_in_map(),
+            count_in_player: flags.get_count_in_player(),
+            crafted: flags.get_crafted(),
+            deloot: flags.get_deloot(),
+        }
+    }
+}
+
+impl From<FlagsRecord> for Flags {
+    fn from(record: FlagsRecord) -> Self {
+        let mut flags = Self::default();
+        flags.set_count_in_cargo(record.count_in_cargo);
+        flags.set_count_in_hoarder(record.count_in_hoarder);
+        flags.set_count_in_map(record.count_in_map);
+        flags.set_count_in_player(record.count_in_player);
+        flags.set_crafted(record.crafted);
+        flags.set_deloot(record.deloot);
+        flags
+    }
+}
+
+fn names(named: Option<&[Named]>) -> Option<Vec<String>> {
+    named.map(|named| named.iter().map(ToString::to_string).collect())
+}
+
+fn named(names: Option<Vec<String>>) -> Option<Vec<Named>> {
+    names.map(|names| {
+        names
+            .iter()
+            .map(String::as_str)
+            .map(Named::from_text)
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypeRecord;
+    use crate::{Named, Type};
+
+    #[test]
+    fn converts_types_back_and_forth() {
+        let mut typ = Type::builder("Apple").build().unwrap();
+        typ.set_category(Some(&Named::from_text("user:food")));
+        typ.set_tags(Some(&[]));
+        typ.set_usages(Some(&[
+            Named::from_text("Town"),
+            Named::from_text("user:Coast"),
+        ]));
+        assert_eq!(Type::try_from(TypeRecord::from(&typ)).unwrap(), typ);
+    }
+}
@@ -1,11 +1,27 @@
+pub mod adjust;
+pub mod csv;
+pub mod dedup;
+pub mod diff;
+pub mod document;
+pub mod economycore;
+pub mod events;
+pub mod eventspawns;
+pub mod filter;
 mod flags;
+pub mod format;
+pub mod limits;
+pub mod merge;
+pub mod mission;
 mod named;
 mod raw;
+pub mod recovery;
+pub mod spawnabletypes;
 mod r#type;
 mod types;
 mod util;
+pub mod validate;
 
 pub use flags::Flags;
 pub use named::Named;
-pub use r#type::Type;
-pub use types::Types;
+pub use r#type::{Type, TypeBuilder, DEFAULT_COST, DEFAULT_LIFETIME};
+pub use types::{CaseSensitivity, Location, TypeMut, Types};
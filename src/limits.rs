@@ -0,0 +1,195 @@
+//! Parsing of `cfglimitsdefinition.xml` and `cfglimitsdefinitionuser.xml`.
+
+mod user;
+
+use crate::validate::{Diagnostic, Rule, Subject};
+use crate::{Named, Type, Types};
+use serde::Deserialize;
+use serde_rw::FromFile;
+
+pub use user::{UserGroup, UserLimitsDefinition};
+
+/// The declared categories, tags, usages and values of a mission
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename = "lists")]
+pub struct LimitsDefinition {
+    #[serde(default)]
+    categories: Categories,
+    #[serde(default)]
+    tags: Tags,
+    #[serde(rename = "usageflags", default)]
+    usages: Usages,
+    #[serde(rename = "valueflags", default)]
+    values: Values,
+}
+
+impl LimitsDefinition {
+    /// Read the limits definition from the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the deserialization fails.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        Self::from_file(filename)
+    }
+
+    #[must_use]
+    pub fn categories(&self) -> &[Named] {
+        &self.categories.categories
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[Named] {
+        &self.tags.tags
+    }
+
+    #[must_use]
+    pub fn usages(&self) -> &[Named] {
+        &self.usages.usages
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &[Named] {
+        &self.values.values
+    }
+
+    /// Check all categories, tags, usages and values of the given types against this definition.
+    ///
+    /// The diagnostics point to the offending names if the types were read from a file.
+    #[must_use]
+    pub fn validate(&self, types: &Types) -> Vec<Diagnostic> {
+        types
+            .types()
+            .enumerate()
+            .flat_map(|(position, typ)| {
+                self.validate_type(typ)
+                    .into_iter()
+                    .map(move |diagnostic| diagnostic.locate(types, position))
+            })
+            .collect()
+    }
+
+    /// Check the categories, tags, usages and values of the given type against this definition.
+    #[must_use]
+    pub fn validate_type(&self, typ: &Type) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let subject = Subject::Type(typ.get_name().to_string());
+
+        if let Some(category) = typ.get_category() {
+            diagnostics.extend(check(
+                Rule::UnknownCategory,
+                &subject,
+                category,
+                &names(self.categories()),
+            ));
+        }
+
+        for (rule, items, declared) in [
+            (Rule::UnknownTag, typ.get_tags(), self.tags()),
+            (Rule::UnknownUsage, typ.get_usages(), self.usages()),
+            (Rule::UnknownValue, typ.get_values(), self.values()),
+        ] {
+            let declared = names(declared);
+            diagnostics.extend(
+                items
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|item| !item.is_user())
+                    .filter_map(|item| check(rule, &subject, item, &declared)),
+            );
+        }
+
+        diagnostics
+    }
+
+    /// Check the members of the given user-defined groupings against this definition.
+    #[must_use]
+    pub fn validate_user(&self, user: &UserLimitsDefinition) -> Vec<Diagnostic> {
+        let declared_usages = names(self.usages());
+        let declared_values = names(self.values());
+        let mut diagnostics = Vec::new();
+
+        for (rule, groups, declared) in [
+            (Rule::UnknownUsage, user.usages(), &declared_usages),
+            (Rule::UnknownValue, user.values(), &declared_values),
+        ] {
+            for group in groups {
+                let subject = Subject::UserGroup(group.name().to_string());
+                diagnostics.extend(
+                    group
+                        .members()
+                        .iter()
+                        .filter_map(|member| check(rule, &subject, member, declared)),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+struct Categories {
+    #[serde(rename = "category", default)]
+    categories: Vec<Named>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+struct Tags {
+    #[serde(rename = "tag", default)]
+    tags: Vec<Named>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+struct Usages {
+    #[serde(rename = "usage", default)]
+    usages: Vec<Named>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+struct Values {
+    #[serde(rename = "value", default)]
+    values: Vec<Named>,
+}
+
+/// Return a diagnostic if `item` is not among the `declared` names.
+///
+/// Names are compared case-insensitively, like the game does.
+fn check(rule: Rule, subject: &Subject, item: &Named, declared: &[&str]) -> Option<Diagnostic> {
+    if declared
+        .iter()
+        .any(|known| known.eq_ignore_ascii_case(item.name()))
+    {
+        return None;
+    }
+
+    let diagnostic =
+        Diagnostic::concerning(rule, subject.clone(), format!("\"{item}\" is not declared"))
+            .with_item(item.name());
+    Some(match suggest(item.name(), declared.iter().copied()) {
+        Some(suggestion) => diagnostic.with_suggestion(suggestion),
+        None => diagnostic,
+    })
+}
+
+fn names(named: &[Named]) -> Vec<&str> {
+    named.iter().map(Named::name).collect()
+}
+
+/// Return the declared name most similar to `name`, if any is similar enough.
+fn suggest<'a>(name: &str, declared: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let name = name.to_ascii_lowercase();
+    let threshold = (name.len() / 3).max(2);
+    declared
+        .map(|known| {
+            (
+                strsim::levenshtein(&name, &known.to_ascii_lowercase()),
+                known,
+            )
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
@@ -0,0 +1,102 @@
+use crate::limits::check;
+use crate::validate::{Diagnostic, Rule, Subject};
+use crate::{Named, Type, Types};
+use serde::Deserialize;
+use serde_rw::FromFile;
+
+/// User-defined groupings of usages and values
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename = "user_lists")]
+pub struct UserLimitsDefinition {
+    #[serde(rename = "usageflags", default)]
+    usages: Groups,
+    #[serde(rename = "valueflags", default)]
+    values: Groups,
+}
+
+impl UserLimitsDefinition {
+    /// Read the user limits definition from the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the deserialization fails.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        Self::from_file(filename)
+    }
+
+    #[must_use]
+    pub fn usages(&self) -> &[UserGroup] {
+        &self.usages.groups
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &[UserGroup] {
+        &self.values.groups
+    }
+
+    /// Check the user group references of the given types against this definition.
+    ///
+    /// The diagnostics point to the offending references if the types were read from a file.
+    #[must_use]
+    pub fn validate(&self, types: &Types) -> Vec<Diagnostic> {
+        types
+            .types()
+            .enumerate()
+            .flat_map(|(position, typ)| {
+                self.validate_type(typ)
+                    .into_iter()
+                    .map(move |diagnostic| diagnostic.locate(types, position))
+            })
+            .collect()
+    }
+
+    /// Check the usages and values of the given type that refer to user groups, e.g. `<usage user="Town"/>`.
+    #[must_use]
+    pub fn validate_type(&self, typ: &Type) -> Vec<Diagnostic> {
+        let subject = Subject::Type(typ.get_name().to_string());
+        [
+            (Rule::UnknownUsage, typ.get_usages(), self.usages()),
+            (Rule::UnknownValue, typ.get_values(), self.values()),
+        ]
+        .into_iter()
+        .flat_map(|(rule, items, groups)| {
+            let declared: Vec<&str> = groups.iter().map(UserGroup::name).collect();
+            items
+                .unwrap_or_default()
+                .iter()
+                .filter(|item| item.is_user())
+                .filter_map(|item| check(rule, &subject, item, &declared))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+    }
+}
+
+/// A named grouping of usages or values
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct UserGroup {
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(rename = "$value", default)]
+    members: Vec<Named>,
+}
+
+impl UserGroup {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    #[must_use]
+    pub fn members(&self) -> &[Named] {
+        &self.members
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+struct Groups {
+    #[serde(rename = "user", default)]
+    groups: Vec<UserGroup>,
+}
@@ -0,0 +1,17 @@
+//! Merging of types collections.
+
+mod conflict;
+mod describe;
+mod merger;
+mod order;
+mod strategy;
+mod three_way;
+mod two_way;
+
+pub use conflict::Conflict;
+pub(crate) use describe::Describe;
+pub(crate) use merger::Merger;
+pub use order::Order;
+pub use strategy::Strategy;
+pub use three_way::ThreeWayMerge;
+pub use two_way::TwoWayMerge;
@@ -0,0 +1,61 @@
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// A conflict between our and their changes
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Conflict {
+    /// Both sides changed a field to different values.
+    Field {
+        name: String,
+        field: String,
+        base: Option<String>,
+        ours: String,
+        theirs: String,
+    },
+    /// We removed a type which they modified.
+    RemovedByUs { name: String },
+    /// They removed a type which we modified.
+    RemovedByThem { name: String },
+}
+
+impl Conflict {
+    /// Return the name of the conflicting type.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Field { name, .. }
+            | Self::RemovedByUs { name }
+            | Self::RemovedByThem { name } => name.as_str(),
+        }
+    }
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field {
+                name,
+                field,
+                base,
+                ours,
+                theirs,
+            } => {
+                write!(
+                    f,
+                    "{name}: {field} changed to {ours} by us and to {theirs} by them"
+                )?;
+
+                if let Some(base) = base {
+                    write!(f, " (was {base})")?;
+                }
+
+                Ok(())
+            }
+            Self::RemovedByUs { name } => write!(f, "{name}: removed by us but modified by them"),
+            Self::RemovedByThem { name } => {
+                write!(f, "{name}: removed by them but modified by us")
+            }
+        }
+    }
+}
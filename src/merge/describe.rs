@@ -0,0 +1,55 @@
+use crate::Named;
+
+/// Describe a field value in a conflict report.
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl Describe for bool {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Describe for u32 {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Describe for i64 {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Describe for Named {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T> Describe for Vec<T>
+where
+    T: Describe,
+{
+    fn describe(&self) -> String {
+        format!(
+            "[{}]",
+            self.iter()
+                .map(Describe::describe)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<T> Describe for Option<T>
+where
+    T: Describe,
+{
+    fn describe(&self) -> String {
+        self.as_ref()
+            .map_or_else(|| "(unset)".to_string(), Describe::describe)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::merge::{Conflict, Describe};
+
+/// Merges the fields of one type and collects the conflicts
+pub struct Merger<'name> {
+    name: &'name str,
+    conflicts: Vec<Conflict>,
+}
+
+impl<'name> Merger<'name> {
+    pub const fn new(name: &'name str) -> Self {
+        Self {
+            name,
+            conflicts: Vec::new(),
+        }
+    }
+
+    /// Merge a single field.
+    ///
+    /// Takes their value if we did not change it, ours otherwise.
+    /// If both sides changed it differently, ours is kept and a conflict is recorded.
+    pub fn merge<T>(&mut self, field: &str, base: Option<&T>, ours: &T, theirs: &T) -> T
+    where
+        T: Clone + Describe + PartialEq,
+    {
+        if ours == theirs || base == Some(theirs) {
+            return ours.clone();
+        }
+
+        if base == Some(ours) {
+            return theirs.clone();
+        }
+
+        self.conflicts.push(Conflict::Field {
+            name: self.name.to_string(),
+            field: field.to_string(),
+            base: base.map(Describe::describe),
+            ours: ours.describe(),
+            theirs: theirs.describe(),
+        });
+        ours.clone()
+    }
+
+    pub fn into_conflicts(self) -> Vec<Conflict> {
+        self.conflicts
+    }
+}
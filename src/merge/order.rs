@@ -0,0 +1,9 @@
+/// The order of the merged types
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Order {
+    /// Keep the base's order and append new types in the extension's order.
+    Preserve,
+    /// Sort all types by name.
+    #[default]
+    Sort,
+}
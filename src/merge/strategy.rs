@@ -0,0 +1,11 @@
+/// How to resolve types present in both the base and the extension
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Strategy {
+    /// Keep the base's type and ignore the extension's.
+    BaseWins,
+    /// Replace the base's type with the extension's.
+    #[default]
+    ExtensionWins,
+    /// Override only those fields of the base's type that are present in the extension's.
+    Overlay,
+}
@@ -0,0 +1,34 @@
+use crate::merge::Conflict;
+use crate::Types;
+
+/// The result of a three-way merge
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThreeWayMerge {
+    types: Types,
+    conflicts: Vec<Conflict>,
+}
+
+impl ThreeWayMerge {
+    #[must_use]
+    pub const fn new(types: Types, conflicts: Vec<Conflict>) -> Self {
+        Self { types, conflicts }
+    }
+
+    /// Return the merged types.
+    ///
+    /// Conflicting fields keep our values.
+    #[must_use]
+    pub const fn types(&self) -> &Types {
+        &self.types
+    }
+
+    #[must_use]
+    pub fn conflicts(&self) -> &[Conflict] {
+        &self.conflicts
+    }
+
+    #[must_use]
+    pub fn into_types(self) -> Types {
+        self.types
+    }
+}
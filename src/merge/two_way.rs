@@ -0,0 +1,42 @@
+use crate::Types;
+
+/// The result of merging an extension into a base
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TwoWayMerge {
+    types: Types,
+    overridden: Vec<String>,
+    ignored: Vec<String>,
+}
+
+impl TwoWayMerge {
+    #[must_use]
+    pub const fn new(types: Types, overridden: Vec<String>, ignored: Vec<String>) -> Self {
+        Self {
+            types,
+            overridden,
+            ignored,
+        }
+    }
+
+    #[must_use]
+    pub const fn types(&self) -> &Types {
+        &self.types
+    }
+
+    /// Return the names of the base's types that were changed by the extension.
+    #[must_use]
+    pub fn overridden(&self) -> &[String] {
+        &self.overridden
+    }
+
+    /// Return the names of the extension's types that were discarded in favor of the base's.
+    #[must_use]
+    pub fn ignored(&self) -> &[String] {
+        &self.ignored
+    }
+
+    #[must_use]
+    pub fn into_types(self) -> Types {
+        self.types
+    }
+}
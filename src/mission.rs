@@ -0,0 +1,457 @@
+//! Loading of a whole mission's central economy via `cfgeconomycore.xml`.
+
+mod definition;
+mod file;
+
+use crate::economycore::{self, EconomyCore, Registration};
+use crate::events::{Event, Events};
+use crate::eventspawns::EventSpawns;
+use crate::limits::{LimitsDefinition, UserLimitsDefinition};
+use crate::spawnabletypes::{SpawnableType, SpawnableTypes};
+use crate::validate::{validate, Diagnostic, Rule, Subject};
+use crate::{CaseSensitivity, Type, Types};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub use definition::Definition;
+pub use file::File;
+
+const TYPES: &str = "types";
+const SPAWNABLE_TYPES: &str = "spawnabletypes";
+const EVENTS: &str = "events";
+
+/// The central economy files of a mission directory
+///
+/// Items defined in several files are resolved like the game does: the last one loaded wins.
+#[derive(Clone, Debug)]
+pub struct Mission {
+    root: PathBuf,
+    economy_core: PathBuf,
+    registrations: Vec<Registration>,
+    missing: Vec<Registration>,
+    types: Vec<File<Types>>,
+    spawnable_types: Vec<File<SpawnableTypes>>,
+    events: Vec<File<Events>>,
+    event_spawns: Option<File<EventSpawns>>,
+    limits: Option<File<LimitsDefinition>>,
+    user_limits: Option<File<UserLimitsDefinition>>,
+}
+
+impl Mission {
+    /// Load the mission in the given directory.
+    ///
+    /// The vanilla files `db/types.xml`, `db/events.xml`, `cfgspawnabletypes.xml`,
+    /// `cfgeventspawns.xml`, `cfglimitsdefinition.xml` and `cfglimitsdefinitionuser.xml`
+    /// are loaded if present, followed by the files registered in `cfgeconomycore.xml`.
+    /// Registered files that do not exist are skipped and reported by [`Self::validate`].
+    ///
+    /// # Arguments
+    /// * `path` - The mission directory or the path to its `cfgeconomycore.xml`.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if `cfgeconomycore.xml` or any of the existing files cannot be read.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, anyhow::Error> {
+        let path = path.as_ref();
+        let root = if path.is_dir() {
+            path.to_path_buf()
+        } else {
+            path.parent().map_or_else(PathBuf::new, Path::to_path_buf)
+        };
+        let economy_core = root.join(economycore::FILE_NAME);
+        let content = read(&economy_core, EconomyCore::read)?.into_content();
+        let missing = content.missing(&root);
+        let registrations = content.registrations();
+        let mut mission = Self {
+            root,
+            economy_core,
+            registrations: Vec::new(),
+            missing,
+            types: Vec::new(),
+            spawnable_types: Vec::new(),
+            events: Vec::new(),
+            event_spawns: None,
+            limits: None,
+            user_limits: None,
+        };
+
+        for (kind, path) in [
+            (TYPES, mission.root.join("db").join("types.xml")),
+            (EVENTS, mission.root.join("db").join("events.xml")),
+            (SPAWNABLE_TYPES, mission.root.join("cfgspawnabletypes.xml")),
+        ] {
+            if path.is_file() {
+                mission.load_file(kind, path)?;
+            }
+        }
+
+        for registration in &registrations {
+            let path = registration.path(&mission.root);
+
+            if !mission.missing.contains(registration) && !mission.is_loaded(&path) {
+                mission.load_file(registration.kind(), path)?;
+            }
+        }
+
+        mission.registrations = registrations;
+        mission.event_spawns =
+            read_optional(&mission.root.join("cfgeventspawns.xml"), EventSpawns::read)?;
+        mission.limits = read_optional(
+            &mission.root.join("cfglimitsdefinition.xml"),
+            LimitsDefinition::read,
+        )?;
+        mission.user_limits = read_optional(
+            &mission.root.join("cfglimitsdefinitionuser.xml"),
+            UserLimitsDefinition::read,
+        )?;
+        Ok(mission)
+    }
+
+    /// Replace the limits definition with the one in the given file.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file cannot be read.
+    pub fn load_limits(&mut self, path: impl AsRef<Path>) -> Result<(), anyhow::Error> {
+        self.limits = Some(read(path.as_ref(), LimitsDefinition::read)?);
+        Ok(())
+    }
+
+    /// Replace the user limits definition with the one in the given file.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file cannot be read.
+    pub fn load_user_limits(&mut self, path: impl AsRef<Path>) -> Result<(), anyhow::Error> {
+        self.user_limits = Some(read(path.as_ref(), UserLimitsDefinition::read)?);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Return the files registered in `cfgeconomycore.xml`.
+    #[must_use]
+    pub fn registrations(&self) -> &[Registration] {
+        &self.registrations
+    }
+
+    /// Return the files registered in `cfgeconomycore.xml` that do not exist.
+    #[must_use]
+    pub fn missing(&self) -> &[Registration] {
+        &self.missing
+    }
+
+    /// Return the loaded types files in load order.
+    #[must_use]
+    pub fn types_files(&self) -> &[File<Types>] {
+        &self.types
+    }
+
+    /// Return the loaded spawnable types files in load order.
+    #[must_use]
+    pub fn spawnable_types_files(&self) -> &[File<SpawnableTypes>] {
+        &self.spawnable_types
+    }
+
+    /// Return the loaded events files in load order.
+    #[must_use]
+    pub fn events_files(&self) -> &[File<Events>] {
+        &self.events
+    }
+
+    #[must_use]
+    pub const fn event_spawns(&self) -> Option<&File<EventSpawns>> {
+        self.event_spawns.as_ref()
+    }
+
+    #[must_use]
+    pub const fn limits(&self) -> Option<&File<LimitsDefinition>> {
+        self.limits.as_ref()
+    }
+
+    #[must_use]
+    pub const fn user_limits(&self) -> Option<&File<UserLimitsDefinition>> {
+        self.user_limits.as_ref()
+    }
+
+    /// Return the types in effect, each from the last file defining it.
+    #[must_use]
+    pub fn types(&self) -> Vec<Definition<'_, Types, Type>> {
+        effective(all_types(&self.types), Type::get_name)
+    }
+
+    /// Return all definitions of the type with the given name in load order.
+    #[must_use]
+    pub fn type_definitions(&self, name: &str) -> Vec<Definition<'_, Types, Type>> {
+        self.types
+            .iter()
+            .flat_map(|file| {
+                let types = file.content();
+                types
+                    .positions(name)
+                    .into_iter()
+                    .filter_map(move |position| {
+                        types
+                            .types()
+                            .as_slice()
+                            .get(position)
+                            .map(|typ| Definition::new(file, typ, position))
+                    })
+            })
+            .collect()
+    }
+
+    /// Return the type with the given name in effect.
+    #[must_use]
+    pub fn get_type(&self, name: &str) -> Option<Definition<'_, Types, Type>> {
+        self.type_definitions(name).pop()
+    }
+
+    /// Return the spawnable types in effect, each from the last file defining it.
+    #[must_use]
+    pub fn spawnable_types(&self) -> Vec<Definition<'_, SpawnableTypes, SpawnableType>> {
+        effective(
+            self.spawnable_types.iter().flat_map(|file| {
+                file.content()
+                    .types()
+                    .enumerate()
+                    .map(move |(position, typ)| Definition::new(file, typ, position))
+            }),
+            SpawnableType::get_name,
+        )
+    }
+
+    /// Return the spawnable type with the given name in effect.
+    #[must_use]
+    pub fn get_spawnable_type(
+        &self,
+        name: &str,
+    ) -> Option<Definition<'_, SpawnableTypes, SpawnableType>> {
+        self.spawnable_types()
+            .into_iter()
+            .find(|definition| definition.item().get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Return the events in effect, each from the last file defining it.
+    #[must_use]
+    pub fn events(&self) -> Vec<Definition<'_, Events, Event>> {
+        effective(
+            self.events.iter().flat_map(|file| {
+                file.content()
+                    .events()
+                    .enumerate()
+                    .map(move |(position, event)| Definition::new(file, event, position))
+            }),
+            Event::get_name,
+        )
+    }
+
+    /// Return the event with the given name in effect.
+    #[must_use]
+    pub fn get_event(&self, name: &str) -> Option<Definition<'_, Events, Event>> {
+        self.events()
+            .into_iter()
+            .find(|definition| definition.item().get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Validate the whole mission.
+    ///
+    /// Reports registered files that do not exist,
+    /// checks every types file on its own and against the limits definition,
+    /// reports types overridden by later files and checks the events in effect
+    /// against their spawn positions.
+    /// Each diagnostic comes with the path of the file it concerns.
+    #[must_use]
+    pub fn validate(&self) -> Vec<(&Path, Diagnostic)> {
+        let mut diagnostics: Vec<(&Path, Diagnostic)> = self
+            .missing
+            .iter()
+            .map(|registration| {
+                (
+                    self.economy_core.as_path(),
+                    Diagnostic::concerning(
+                        Rule::MissingFile,
+                        Subject::File(registration.to_string()),
+                        "registered in cfgeconomycore.xml but does not exist".to_string(),
+                    ),
+                )
+            })
+            .collect();
+
+        for file in &self.types {
+            let types = file.content();
+            diagnostics.extend(
+                validate(types)
+                    .into_iter()
+                    .map(|diagnostic| (file.path(), diagnostic)),
+            );
+
+            if let Some(ref limits) = self.limits {
+                diagnostics.extend(
+                    limits
+                        .content()
+                        .validate(types)
+                        .into_iter()
+                        .map(|diagnostic| (file.path(), diagnostic)),
+                );
+            }
+
+            if let Some(ref user_limits) = self.user_limits {
+                diagnostics.extend(
+                    user_limits
+                        .content()
+                        .validate(types)
+                        .into_iter()
+                        .map(|diagnostic| (file.path(), diagnostic)),
+                );
+            }
+        }
+
+        if let (Some(limits), Some(user_limits)) = (&self.limits, &self.user_limits) {
+            diagnostics.extend(
+                limits
+                    .content()
+                    .validate_user(user_limits.content())
+                    .into_iter()
+                    .map(|diagnostic| (user_limits.path(), diagnostic)),
+            );
+        }
+
+        let mut definitions: HashMap<String, Vec<Definition<'_, Types, Type>>> = HashMap::new();
+
+        for definition in all_types(&self.types) {
+            definitions
+                .entry(definition.item().get_name().to_ascii_lowercase())
+                .or_default()
+                .push(definition);
+        }
+
+        for winner in self.types() {
+            let Some(copies) = definitions.get(&winner.item().get_name().to_ascii_lowercase())
+            else {
+                continue;
+            };
+
+            for overridden in copies
+                .iter()
+                .filter(|definition| !std::ptr::eq(definition.item(), winner.item()))
+            {
+                let message = if std::ptr::eq(overridden.file(), winner.file()) {
+                    "defined again later in the same file".to_string()
+                } else {
+                    format!("overridden by {}", winner.path().display())
+                };
+                diagnostics.push((
+                    overridden.path(),
+                    Diagnostic::new(Rule::TypeOverridden, overridden.item().get_name(), message)
+                        .locate(overridden.file().content(), overridden.position()),
+                ));
+            }
+        }
+
+        if let Some(ref spawns) = self.event_spawns {
+            let effective = self.events();
+            let mut events = Events::default();
+            let mut paths = HashMap::with_capacity(effective.len());
+
+            for definition in effective {
+                events.add(definition.item().clone());
+                paths.insert(
+                    definition.item().get_name().to_ascii_lowercase(),
+                    definition.path(),
+                );
+            }
+
+            for diagnostic in spawns.content().validate(&events) {
+                let path = match diagnostic.rule() {
+                    Rule::EventWithoutSpawns => paths
+                        .get(&diagnostic.subject().name().to_ascii_lowercase())
+                        .copied()
+                        .unwrap_or_else(|| spawns.path()),
+                    _ => spawns.path(),
+                };
+                diagnostics.push((path, diagnostic));
+            }
+        }
+
+        diagnostics
+    }
+
+    fn is_loaded(&self, path: &Path) -> bool {
+        self.types
+            .iter()
+            .map(File::path)
+            .chain(self.spawnable_types.iter().map(File::path))
+            .chain(self.events.iter().map(File::path))
+            .any(|loaded| loaded == path)
+    }
+
+    fn load_file(&mut self, kind: &str, path: PathBuf) -> Result<(), anyhow::Error> {
+        match kind {
+            TYPES => {
+                let mut file = read(&path, Types::read)?;
+                file.mut_content()
+                    .set_case_sensitivity(CaseSensitivity::Insensitive);
+                self.types.push(file);
+            }
+            SPAWNABLE_TYPES => self
+                .spawnable_types
+                .push(read(&path, SpawnableTypes::read)?),
+            EVENTS => self.events.push(read(&path, Events::read)?),
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+fn read<T>(
+    path: &Path,
+    read: fn(&str) -> Result<T, anyhow::Error>,
+) -> Result<File<T>, anyhow::Error> {
+    read(&path.to_string_lossy())
+        .map(|content| File::new(path.to_path_buf(), content))
+        .map_err(|error| anyhow::Error::msg(format!("{}\n{error}", path.display())))
+}
+
+fn read_optional<T>(
+    path: &Path,
+    read_file: fn(&str) -> Result<T, anyhow::Error>,
+) -> Result<Option<File<T>>, anyhow::Error> {
+    if path.is_file() {
+        read(path, read_file).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn all_types(files: &[File<Types>]) -> impl Iterator<Item = Definition<'_, Types, Type>> {
+    files.iter().flat_map(|file| {
+        file.content()
+            .types()
+            .enumerate()
+            .map(move |(position, typ)| Definition::new(file, typ, position))
+    })
+}
+
+/// Keep the last definition of each name at the position of its first one.
+fn effective<'mission, C, T>(
+    definitions: impl Iterator<Item = Definition<'mission, C, T>>,
+    name: fn(&T) -> &str,
+) -> Vec<Definition<'mission, C, T>> {
+    let mut effective: Vec<Definition<'mission, C, T>> = Vec::new();
+    let mut positions = HashMap::new();
+
+    for definition in definitions {
+        let key = name(definition.item()).to_ascii_lowercase();
+
+        if let Some(&position) = positions.get(&key) {
+            effective[position] = definition;
+        } else {
+            positions.insert(key, effective.len());
+            effective.push(definition);
+        }
+    }
+
+    effective
+}
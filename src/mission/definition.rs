@@ -0,0 +1,51 @@
+use crate::mission::File;
+use std::path::Path;
+
+/// An item of a mission together with the file defining it
+#[derive(Debug)]
+pub struct Definition<'mission, C, T> {
+    file: &'mission File<C>,
+    item: &'mission T,
+    position: usize,
+}
+
+impl<'mission, C, T> Definition<'mission, C, T> {
+    pub(crate) const fn new(file: &'mission File<C>, item: &'mission T, position: usize) -> Self {
+        Self {
+            file,
+            item,
+            position,
+        }
+    }
+
+    /// Return the file defining the item.
+    #[must_use]
+    pub const fn file(&self) -> &'mission File<C> {
+        self.file
+    }
+
+    /// Return the path of the file defining the item.
+    #[must_use]
+    pub fn path(&self) -> &'mission Path {
+        self.file.path()
+    }
+
+    #[must_use]
+    pub const fn item(&self) -> &'mission T {
+        self.item
+    }
+
+    /// Return the position of the item within its file.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<C, T> Clone for Definition<'_, C, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, T> Copy for Definition<'_, C, T> {}
@@ -0,0 +1,32 @@
+use std::path::{Path, PathBuf};
+
+/// A loaded file of a mission
+#[derive(Clone, Debug, PartialEq)]
+pub struct File<T> {
+    path: PathBuf,
+    content: T,
+}
+
+impl<T> File<T> {
+    pub(crate) const fn new(path: PathBuf, content: T) -> Self {
+        Self { path, content }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn content(&self) -> &T {
+        &self.content
+    }
+
+    pub(crate) fn mut_content(&mut self) -> &mut T {
+        &mut self.content
+    }
+
+    pub(crate) fn into_content(self) -> T {
+        self.content
+    }
+}
@@ -1,32 +1,99 @@
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{Display, Formatter};
 
-#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+/// The prefix marking a reference to a user-defined group in textual representations.
+const USER_PREFIX: &str = "user:";
+
+/// A named category, tag, usage or value
+///
+/// Usages and values may instead refer to a group of `cfglimitsdefinitionuser.xml`
+/// via the `user` attribute, e.g. `<usage user="Town"/>`.
+/// Such references are written as `user:NAME` in text.
+#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd)]
+#[serde(try_from = "Attributes")]
 pub struct Named {
-    #[serde(rename = "@name")]
     name: String,
+    user: bool,
 }
 
 impl Named {
     #[must_use]
     pub const fn new(name: String) -> Self {
-        Self { name }
+        Self { name, user: false }
+    }
+
+    /// Create a reference to the user-defined group of the given name.
+    #[must_use]
+    pub const fn user(name: String) -> Self {
+        Self { name, user: true }
+    }
+
+    /// Parse the textual representation, i.e. `NAME` or `user:NAME`.
+    #[must_use]
+    pub fn from_text(text: &str) -> Self {
+        text.strip_prefix(USER_PREFIX).map_or_else(
+            || Self::new(text.to_string()),
+            |name| Self::user(name.to_string()),
+        )
     }
 
     #[must_use]
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// Return whether this refers to a user-defined group.
+    #[must_use]
+    pub const fn is_user(&self) -> bool {
+        self.user
+    }
 }
 
 impl Display for Named {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.user {
+            write!(f, "{USER_PREFIX}")?;
+        }
+
         <String as Display>::fmt(&self.name, f)
     }
 }
 
 impl From<String> for Named {
     fn from(name: String) -> Self {
-        Self { name }
+        Self::new(name)
+    }
+}
+
+impl Serialize for Named {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Named", 1)?;
+        state.serialize_field(if self.user { "@user" } else { "@name" }, &self.name)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct Attributes {
+    #[serde(rename = "@name")]
+    name: Option<String>,
+    #[serde(rename = "@user")]
+    user: Option<String>,
+}
+
+impl TryFrom<Attributes> for Named {
+    type Error = &'static str;
+
+    fn try_from(attributes: Attributes) -> Result<Self, Self::Error> {
+        match (attributes.name, attributes.user) {
+            (Some(name), None) => Ok(Self::new(name)),
+            (None, Some(user)) => Ok(Self::user(user)),
+            (Some(_), Some(_)) => Err("attributes name and user are mutually exclusive"),
+            (None, None) => Err("missing attribute name"),
+        }
     }
 }
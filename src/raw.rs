@@ -20,6 +20,8 @@ pub struct Type {
     pub(crate) cost: Option<String>,
     pub(crate) flags: Option<Flags>,
     pub(crate) category: Option<Named>,
+    #[serde(rename = "tag")]
+    pub(crate) tags: Option<Vec<Named>>,
     #[serde(rename = "usage")]
     pub(crate) usages: Option<Vec<Named>>,
     #[serde(rename = "value")]
@@ -46,4 +48,6 @@ pub struct Flags {
 pub struct Named {
     #[serde(rename = "@name")]
     pub(crate) name: Option<String>,
+    #[serde(rename = "@user")]
+    pub(crate) user: Option<String>,
 }
@@ -0,0 +1,100 @@
+//! Reports of values that were corrected or lost while reading types gracefully.
+
+mod kind;
+
+use crate::document::Position;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+pub use kind::Kind;
+
+/// A value that could not be read as is
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Recovery {
+    kind: Kind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    type_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<Position>,
+    message: String,
+}
+
+impl Recovery {
+    #[must_use]
+    pub fn new(kind: Kind, type_name: Option<&str>, field: Option<&str>, message: String) -> Self {
+        Self {
+            kind,
+            type_name: type_name.map(ToString::to_string),
+            field: field.map(ToString::to_string),
+            original: None,
+            position: None,
+            message,
+        }
+    }
+
+    #[must_use]
+    pub fn with_original(mut self, original: Option<&str>) -> Self {
+        self.original = original.map(ToString::to_string);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_position(mut self, position: Option<Position>) -> Self {
+        self.position = position;
+        self
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    #[must_use]
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// Return the original text of the value, if there was any.
+    #[must_use]
+    pub fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl Display for Recovery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(position) = self.position {
+            write!(f, "{position}: ")?;
+        }
+
+        write!(f, "{}", self.kind)?;
+
+        match (&self.type_name, &self.field) {
+            (Some(type_name), Some(field)) => write!(f, " {type_name}.{field}")?,
+            (Some(type_name), None) => write!(f, " {type_name}")?,
+            (None, Some(field)) => write!(f, " {field}")?,
+            (None, None) => {}
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
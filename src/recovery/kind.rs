@@ -0,0 +1,24 @@
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// How a value that could not be read as is was dealt with
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    /// The value was reinterpreted without changing its meaning.
+    Recovered,
+    /// A missing mandatory value was replaced with a default.
+    Defaulted,
+    /// The value was discarded.
+    Lost,
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Recovered => write!(f, "recovered"),
+            Self::Defaulted => write!(f, "defaulted"),
+            Self::Lost => write!(f, "lost"),
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Parsing and editing of `cfgspawnabletypes.xml`.
+
+mod damage;
+mod group;
+mod item;
+mod raw;
+mod spawnable_type;
+
+use crate::document::{Document, Source};
+use serde::{Deserialize, Serialize};
+use serde_rw::{FromFile, ToXml};
+use std::fmt::{Display, Formatter};
+use std::slice::Iter;
+use std::str::FromStr;
+
+pub use damage::Damage;
+pub use group::Group;
+pub use item::Item;
+pub use spawnable_type::SpawnableType;
+
+const DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#;
+
+/// What spawns on and in items, per class
+///
+/// If read from a well-formed document, that document is kept, so that comments,
+/// unknown elements and formatting survive writing the types back.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "spawnabletypes")]
+pub struct SpawnableTypes {
+    #[serde(rename = "type", default)]
+    types: Vec<SpawnableType>,
+    #[serde(skip)]
+    source: Option<Source<SpawnableType>>,
+}
+
+impl SpawnableTypes {
+    /// Parse the cfgspawnabletypes.xml strictly
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file is not well-formed or a type cannot be deserialized.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        std::fs::read_to_string(filename)?.parse()
+    }
+
+    /// Parse the cfgspawnabletypes.xml gracefully
+    ///
+    /// Unknown elements, nameless types and items and invalid chances are dropped,
+    /// and damage bounds are clamped to the range from 0 to 1.
+    /// If the file is not well-formed XML, its comments and formatting are not preserved.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` holding both the document and the deserialization error
+    /// if the deserialization fails.
+    pub fn read_gracefully(filename: &str) -> Result<Self, anyhow::Error> {
+        match Document::read(filename)
+            .and_then(|document| Source::recover(document, SpawnableType::recover_element))
+        {
+            Ok((source, types)) => Ok(Self {
+                types,
+                source: Some(source),
+            }),
+            Err(error) => raw::SpawnableTypes::from_file(filename)
+                .map(Self::from)
+                .map_err(|raw_error| anyhow::Error::msg(format!("{error}\n{raw_error}"))),
+        }
+    }
+
+    /// Write the cfgspawnabletypes.xml to the given file.
+    ///
+    /// Comments and formatting of the file the types were read from are preserved.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to write.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if serialization or writing the file fails.
+    pub fn write(&self, filename: &str) -> Result<(), anyhow::Error> {
+        Ok(std::fs::write(filename, self.render()?)?)
+    }
+
+    pub fn types(&self) -> Iter<'_, SpawnableType> {
+        self.types.iter()
+    }
+
+    /// Return the type with the given name.
+    ///
+    /// Names are compared case-insensitively, like the game does.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&SpawnableType> {
+        self.types
+            .iter()
+            .find(|typ| typ.get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Return the type with the given name mutably.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut SpawnableType> {
+        self.types
+            .iter_mut()
+            .find(|typ| typ.get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Return the type with the given name mutably, adding an empty one if there is none.
+    pub fn get_or_add(&mut self, name: &str) -> &mut SpawnableType {
+        let position = self
+            .types
+            .iter()
+            .position(|typ| typ.get_name().eq_ignore_ascii_case(name))
+            .unwrap_or_else(|| {
+                self.types.push(SpawnableType::new(name));
+                self.types.len() - 1
+            });
+        &mut self.types[position]
+    }
+
+    /// Return whether there is a type with the given name.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Replace the type with the same name or add it if there is none.
+    ///
+    /// Returns the replaced type.
+    pub fn insert(&mut self, typ: SpawnableType) -> Option<SpawnableType> {
+        if let Some(existing) = self.get_mut(typ.get_name()) {
+            Some(std::mem::replace(existing, typ))
+        } else {
+            self.types.push(typ);
+            None
+        }
+    }
+
+    /// Remove the type with the given name.
+    ///
+    /// Returns the removed type.
+    pub fn remove(&mut self, name: &str) -> Option<SpawnableType> {
+        let position = self
+            .types
+            .iter()
+            .position(|typ| typ.get_name().eq_ignore_ascii_case(name))?;
+        Some(self.types.remove(position))
+    }
+
+    fn render(&self) -> Result<String, anyhow::Error> {
+        self.source.as_ref().map_or_else(
+            || Ok(format!("{DECLARATION}\n{}\n", self.to_xml_pretty(' ', 4)?)),
+            |source| Ok(source.render(&self.types)?.to_string()),
+        )
+    }
+}
+
+impl Display for SpawnableTypes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render().map_err(|_| std::fmt::Error)?)
+    }
+}
+
+impl FromStr for SpawnableTypes {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (source, types) = Source::parse(s.parse()?)?;
+        Ok(Self {
+            types,
+            source: Some(source),
+        })
+    }
+}
+
+impl PartialEq for SpawnableTypes {
+    fn eq(&self, other: &Self) -> bool {
+        self.types == other.types
+    }
+}
+
+impl From<raw::SpawnableTypes> for SpawnableTypes {
+    fn from(raw: raw::SpawnableTypes) -> Self {
+        Self {
+            types: raw
+                .types
+                .into_iter()
+                .filter_map(SpawnableType::recover)
+                .collect(),
+            source: None,
+        }
+    }
+}
+
+/// Parse a chance or damage bound, which must lie between 0 and 1.
+fn parse_fraction(field: &str, text: Option<&str>) -> Result<f32, anyhow::Error> {
+    let text = text.ok_or_else(|| anyhow::Error::msg(format!("{field} is missing")))?;
+    text.parse::<f32>()
+        .ok()
+        .filter(|fraction| (0.0..=1.0).contains(fraction))
+        .ok_or_else(|| {
+            anyhow::Error::msg(format!("{field} {text:?} is not a number between 0 and 1"))
+        })
+}
+
+/// Parse a chance or damage bound leniently, clamping it to the range from 0 to 1.
+fn recover_fraction(text: &str) -> Option<f32> {
+    text.trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|fraction| fraction.is_finite())
+        .map(|fraction| fraction.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpawnableTypes;
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<spawnabletypes>
+    <!-- Weapons -->
+    <type name="AK101">
+        <attachments chance="0.30">
+            <item name="AK_WoodBttstck" chance="1.00" />
+            <item name="AK_PlasticBttstck" chance="0.50" />
+        </attachments>
+        <cargo preset="mixArmy" />
+        <damage min="0.45" max="0.85" />
+    </type>
+    <type name="Barrel_Blue">
+        <hoarder />
+    </type>
+</spawnabletypes>
+"#;
+
+    #[test]
+    fn writes_unchanged_spawnable_types_as_read() {
+        let types: SpawnableTypes = DOCUMENT.parse().unwrap();
+        assert_eq!(types.to_string(), DOCUMENT);
+    }
+
+    #[test]
+    fn writes_edits_into_the_original_elements() {
+        let mut types: SpawnableTypes = DOCUMENT.parse().unwrap();
+        types.get_mut("Barrel_Blue").unwrap().set_unique(true);
+        types.remove("AK101");
+        let rendered = types.to_string();
+        assert!(rendered.contains("<!-- Weapons -->"));
+        assert!(rendered.contains("<hoarder />"));
+        assert!(rendered.contains("<unique"));
+        assert!(!rendered.contains("AK101"));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::spawnabletypes::{parse_fraction, raw, recover_fraction};
+use crate::util::{as_decimal, fmt_decimal};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The range of damage an item spawns with
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "raw::Damage")]
+pub struct Damage {
+    #[serde(rename = "@min", serialize_with = "as_decimal")]
+    min: f32,
+    #[serde(rename = "@max", serialize_with = "as_decimal")]
+    max: f32,
+}
+
+impl Damage {
+    #[must_use]
+    pub const fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+
+    #[must_use]
+    pub const fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    #[must_use]
+    pub const fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    /// Read the damage, clamping its bounds and discarding invalid ones.
+    pub(crate) fn recover(raw: raw::Damage) -> Self {
+        let min = raw.min.as_deref().and_then(recover_fraction).unwrap_or(0.0);
+        let max = raw.max.as_deref().and_then(recover_fraction).unwrap_or(1.0);
+        Self::new(min.min(max), max.max(min))
+    }
+}
+
+impl Display for Damage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", fmt_decimal(self.min), fmt_decimal(self.max))
+    }
+}
+
+impl TryFrom<raw::Damage> for Damage {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Damage) -> Result<Self, Self::Error> {
+        let min = parse_fraction("damage min", raw.min.as_deref())?;
+        let max = parse_fraction("damage max", raw.max.as_deref())?;
+
+        if min > max {
+            return Err(anyhow::Error::msg(format!(
+                "damage min {min} exceeds max {max}"
+            )));
+        }
+
+        Ok(Self::new(min, max))
+    }
+}
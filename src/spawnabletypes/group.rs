@@ -0,0 +1,127 @@
+use crate::spawnabletypes::{parse_fraction, raw, recover_fraction, Item};
+use crate::util::{as_optional_decimal, fmt_decimal, fmt_slice};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A set of attachments or cargo items of which one is spawned
+///
+/// Either lists its items or refers to a preset from `cfgrandompresets.xml`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "raw::Group")]
+pub struct Group {
+    #[serde(
+        rename = "@chance",
+        serialize_with = "as_optional_decimal",
+        skip_serializing_if = "Option::is_none"
+    )]
+    chance: Option<f32>,
+    #[serde(rename = "@preset", skip_serializing_if = "Option::is_none")]
+    preset: Option<String>,
+    #[serde(rename = "item")]
+    items: Vec<Item>,
+}
+
+impl Group {
+    /// Create a group of the given items spawning with the given chance.
+    #[must_use]
+    pub const fn new(chance: Option<f32>, items: Vec<Item>) -> Self {
+        Self {
+            chance,
+            preset: None,
+            items,
+        }
+    }
+
+    /// Create a group referring to the given preset.
+    #[must_use]
+    pub fn preset(preset: &str) -> Self {
+        Self {
+            chance: None,
+            preset: Some(preset.to_string()),
+            items: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn get_chance(&self) -> Option<f32> {
+        self.chance
+    }
+
+    #[must_use]
+    pub fn get_preset(&self) -> Option<&str> {
+        self.preset.as_deref()
+    }
+
+    #[must_use]
+    pub fn get_items(&self) -> &[Item] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn mut_items(&mut self) -> &mut Vec<Item> {
+        &mut self.items
+    }
+
+    pub fn set_chance(&mut self, chance: Option<f32>) {
+        self.chance = chance;
+    }
+
+    pub fn set_preset(&mut self, preset: Option<&str>) {
+        self.preset = preset.map(ToString::to_string);
+    }
+
+    /// Return whether the group neither lists items nor refers to a preset.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.preset.is_none() && self.items.is_empty()
+    }
+
+    /// Read the group, discarding an invalid chance and nameless items.
+    pub(crate) fn recover(raw: raw::Group) -> Self {
+        Self {
+            chance: raw.chance.as_deref().and_then(recover_fraction),
+            preset: raw.preset.filter(|preset| !preset.trim().is_empty()),
+            items: raw.items.into_iter().filter_map(Item::recover).collect(),
+        }
+    }
+}
+
+impl Display for Group {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(chance) = self.chance {
+            write!(f, "{} ", fmt_decimal(chance))?;
+        }
+
+        if let Some(ref preset) = self.preset {
+            write!(f, "preset {preset}")?;
+
+            if self.items.is_empty() {
+                return Ok(());
+            }
+
+            write!(f, " ")?;
+        }
+
+        fmt_slice(f, "", &self.items)
+    }
+}
+
+impl TryFrom<raw::Group> for Group {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Group) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chance: raw
+                .chance
+                .as_deref()
+                .map(|chance| parse_fraction("chance", Some(chance)))
+                .transpose()?,
+            preset: raw.preset,
+            items: raw
+                .items
+                .into_iter()
+                .map(Item::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
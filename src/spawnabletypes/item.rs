@@ -0,0 +1,71 @@
+use crate::spawnabletypes::{parse_fraction, raw, recover_fraction};
+use crate::util::{as_decimal, fmt_decimal};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// An item spawned on or in another one with the given chance
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "raw::Item")]
+pub struct Item {
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(rename = "@chance", serialize_with = "as_decimal")]
+    chance: f32,
+}
+
+impl Item {
+    #[must_use]
+    pub fn new(name: &str, chance: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            chance,
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    #[must_use]
+    pub const fn get_chance(&self) -> f32 {
+        self.chance
+    }
+
+    pub fn set_chance(&mut self, chance: f32) {
+        self.chance = chance;
+    }
+
+    /// Read the item, defaulting an invalid chance to 1.
+    ///
+    /// Returns `None` if the item has no name.
+    pub(crate) fn recover(raw: raw::Item) -> Option<Self> {
+        let name = raw.name.filter(|name| !name.trim().is_empty())?;
+        Some(Self::new(
+            name.trim(),
+            raw.chance
+                .as_deref()
+                .and_then(recover_fraction)
+                .unwrap_or(1.0),
+        ))
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, fmt_decimal(self.chance))
+    }
+}
+
+impl TryFrom<raw::Item> for Item {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::Item) -> Result<Self, Self::Error> {
+        let name = raw
+            .name
+            .ok_or_else(|| anyhow::Error::msg("item without name"))?;
+        let chance = parse_fraction("chance", raw.chance.as_deref())
+            .map_err(|error| anyhow::Error::msg(format!("item {name}: {error}")))?;
+        Ok(Self::new(&name, chance))
+    }
+}
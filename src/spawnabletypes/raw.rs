@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename = "spawnabletypes")]
+pub struct SpawnableTypes {
+    #[serde(rename = "type", default)]
+    pub(crate) types: Vec<SpawnableType>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct SpawnableType {
+    #[serde(rename = "@name")]
+    pub(crate) name: Option<String>,
+    #[serde(rename = "$value", default)]
+    pub(crate) children: Vec<Child>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Child {
+    Damage(Damage),
+    Attachments(Group),
+    Cargo(Group),
+    Hoarder,
+    Unique,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Damage {
+    #[serde(rename = "@min")]
+    pub(crate) min: Option<String>,
+    #[serde(rename = "@max")]
+    pub(crate) max: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Group {
+    #[serde(rename = "@chance")]
+    pub(crate) chance: Option<String>,
+    #[serde(rename = "@preset")]
+    pub(crate) preset: Option<String>,
+    #[serde(rename = "item", default)]
+    pub(crate) items: Vec<Item>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Item {
+    #[serde(rename = "@name")]
+    pub(crate) name: Option<String>,
+    #[serde(rename = "@chance")]
+    pub(crate) chance: Option<String>,
+}
@@ -0,0 +1,198 @@
+use crate::document::{Element, Entry};
+use crate::spawnabletypes::raw::{self, Child};
+use crate::spawnabletypes::{Damage, Group};
+use crate::util::{as_empty_element, is_false};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// What spawns on and in items of a class
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "type", try_from = "raw::SpawnableType")]
+pub struct SpawnableType {
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    damage: Option<Damage>,
+    attachments: Vec<Group>,
+    cargo: Vec<Group>,
+    #[serde(serialize_with = "as_empty_element", skip_serializing_if = "is_false")]
+    hoarder: bool,
+    #[serde(serialize_with = "as_empty_element", skip_serializing_if = "is_false")]
+    unique: bool,
+}
+
+impl SpawnableType {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            damage: None,
+            attachments: Vec::new(),
+            cargo: Vec::new(),
+            hoarder: false,
+            unique: false,
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    #[must_use]
+    pub const fn get_damage(&self) -> Option<&Damage> {
+        self.damage.as_ref()
+    }
+
+    #[must_use]
+    pub fn get_attachments(&self) -> &[Group] {
+        &self.attachments
+    }
+
+    #[must_use]
+    pub fn get_cargo(&self) -> &[Group] {
+        &self.cargo
+    }
+
+    #[must_use]
+    pub const fn get_hoarder(&self) -> bool {
+        self.hoarder
+    }
+
+    #[must_use]
+    pub const fn get_unique(&self) -> bool {
+        self.unique
+    }
+
+    #[must_use]
+    pub fn mut_attachments(&mut self) -> &mut Vec<Group> {
+        &mut self.attachments
+    }
+
+    #[must_use]
+    pub fn mut_cargo(&mut self) -> &mut Vec<Group> {
+        &mut self.cargo
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn set_damage(&mut self, damage: Option<Damage>) {
+        self.damage = damage;
+    }
+
+    pub fn set_hoarder(&mut self, hoarder: bool) {
+        self.hoarder = hoarder;
+    }
+
+    pub fn set_unique(&mut self, unique: bool) {
+        self.unique = unique;
+    }
+
+    /// Read the type, skipping unknown elements, invalid values and groups left empty.
+    ///
+    /// Returns `None` if the type has no name.
+    pub(crate) fn recover(raw: raw::SpawnableType) -> Option<Self> {
+        let name = raw.name.filter(|name| !name.trim().is_empty())?;
+        let mut typ = Self::new(name.trim());
+
+        for child in raw.children {
+            match child {
+                Child::Damage(damage) => typ.damage = Some(Damage::recover(damage)),
+                Child::Attachments(group) => typ.attachments.extend(recover_group(group)),
+                Child::Cargo(group) => typ.cargo.extend(recover_group(group)),
+                Child::Hoarder => typ.hoarder = true,
+                Child::Unique => typ.unique = true,
+                Child::Unknown => {}
+            }
+        }
+
+        Some(typ)
+    }
+
+    /// Read the type from its XML element gracefully, see [`SpawnableType::recover`].
+    pub(crate) fn recover_element(element: &Element) -> Result<Option<Self>, anyhow::Error> {
+        Ok(Self::recover(quick_xml::de::from_str(
+            &element.to_string(),
+        )?))
+    }
+}
+
+impl Entry for SpawnableType {
+    const ROOT: &'static str = "spawnabletypes";
+    const ELEMENT: &'static str = "type";
+
+    fn key(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn set_key(&mut self, key: &str) {
+        self.set_name(key);
+    }
+}
+
+impl Display for SpawnableType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "name       :\t{}", self.name)?;
+
+        if let Some(damage) = self.damage {
+            write!(f, "\ndamage     :\t{damage}")?;
+        }
+
+        for (index, group) in self.attachments.iter().enumerate() {
+            write!(f, "\nattachments:\t{}. {group}", index + 1)?;
+        }
+
+        for (index, group) in self.cargo.iter().enumerate() {
+            write!(f, "\ncargo      :\t{}. {group}", index + 1)?;
+        }
+
+        if self.hoarder {
+            write!(f, "\nhoarder")?;
+        }
+
+        if self.unique {
+            write!(f, "\nunique")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<raw::SpawnableType> for SpawnableType {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: raw::SpawnableType) -> Result<Self, Self::Error> {
+        let name = raw
+            .name
+            .ok_or_else(|| anyhow::Error::msg("type without name"))?;
+        let mut typ = Self::new(&name);
+        let context = |error: anyhow::Error| anyhow::Error::msg(format!("type {name}: {error}"));
+
+        for child in raw.children {
+            match child {
+                Child::Damage(damage) => {
+                    if typ.damage.is_some() {
+                        return Err(context(anyhow::Error::msg("duplicate damage")));
+                    }
+
+                    typ.damage = Some(Damage::try_from(damage).map_err(context)?);
+                }
+                Child::Attachments(group) => typ
+                    .attachments
+                    .push(Group::try_from(group).map_err(context)?),
+                Child::Cargo(group) => typ.cargo.push(Group::try_from(group).map_err(context)?),
+                Child::Hoarder => typ.hoarder = true,
+                Child::Unique => typ.unique = true,
+                Child::Unknown => return Err(context(anyhow::Error::msg("unknown element"))),
+            }
+        }
+
+        Ok(typ)
+    }
+}
+
+fn recover_group(raw: raw::Group) -> Option<Group> {
+    Some(Group::recover(raw)).filter(|group| !group.is_empty())
+}
@@ -1,19 +1,30 @@
+mod adjust;
+mod builder;
+mod diff;
+mod element;
+mod lists;
+mod merge;
+mod overlay;
+mod recover;
+
 use crate::util::fmt_slice;
 use crate::{raw, Flags, Named};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
+pub use builder::{TypeBuilder, DEFAULT_COST, DEFAULT_LIFETIME};
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename = "type")]
 pub struct Type {
     #[serde(rename = "@name")]
     name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    nominal: Option<u8>,
+    nominal: Option<u32>,
     lifetime: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
     restock: Option<u32>,
-    min: u8,
+    min: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
     quantmin: Option<i64>,
     quantmax: i64,
@@ -22,6 +33,8 @@ pub struct Type {
     flags: Flags,
     #[serde(skip_serializing_if = "Option::is_none")]
     category: Option<Named>,
+    #[serde(rename = "tag", skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<Named>>,
     #[serde(rename = "usage", skip_serializing_if = "Option::is_none")]
     usages: Option<Vec<Named>>,
     #[serde(rename = "value", skip_serializing_if = "Option::is_none")]
@@ -29,6 +42,9 @@ pub struct Type {
 }
 
 impl Type {
+    /// Create a type with all fields zeroed or unset.
+    ///
+    /// Use [`Type::builder`] for a type with sensible defaults.
     #[must_use]
     pub fn new(name: &str) -> Self {
         Self {
@@ -42,26 +58,111 @@ impl Type {
             cost: None,
             flags: Flags::default(),
             category: None,
+            tags: None,
             usages: None,
             values: None,
         }
     }
 
+    /// Return a builder for a type with sensible defaults.
+    #[must_use]
+    pub fn builder(name: &str) -> TypeBuilder {
+        TypeBuilder::new(name)
+    }
+
     #[must_use]
     pub fn get_name(&self) -> &str {
         self.name.as_str()
     }
 
+    #[must_use]
+    pub const fn get_nominal(&self) -> Option<u32> {
+        self.nominal
+    }
+
+    #[must_use]
+    pub const fn get_lifetime(&self) -> u32 {
+        self.lifetime
+    }
+
+    #[must_use]
+    pub const fn get_restock(&self) -> Option<u32> {
+        self.restock
+    }
+
+    #[must_use]
+    pub const fn get_min(&self) -> u32 {
+        self.min
+    }
+
+    #[must_use]
+    pub const fn get_quantmin(&self) -> Option<i64> {
+        self.quantmin
+    }
+
+    #[must_use]
+    pub const fn get_quantmax(&self) -> i64 {
+        self.quantmax
+    }
+
+    #[must_use]
+    pub const fn get_cost(&self) -> Option<u32> {
+        self.cost
+    }
+
+    #[must_use]
+    pub const fn get_flags(&self) -> &Flags {
+        &self.flags
+    }
+
+    #[must_use]
+    pub const fn get_category(&self) -> Option<&Named> {
+        self.category.as_ref()
+    }
+
+    #[must_use]
+    pub fn get_tags(&self) -> Option<&[Named]> {
+        self.tags.as_deref()
+    }
+
+    #[must_use]
+    pub fn get_usages(&self) -> Option<&[Named]> {
+        self.usages.as_deref()
+    }
+
+    #[must_use]
+    pub fn get_values(&self) -> Option<&[Named]> {
+        self.values.as_deref()
+    }
+
     #[must_use]
     pub fn mut_flags(&mut self) -> &mut Flags {
         &mut self.flags
     }
 
+    /// Return the tags mutably, creating an empty list if there is none.
+    #[must_use]
+    pub fn mut_tags(&mut self) -> &mut Vec<Named> {
+        self.tags.get_or_insert_with(Vec::new)
+    }
+
+    /// Return the usages mutably, creating an empty list if there is none.
+    #[must_use]
+    pub fn mut_usages(&mut self) -> &mut Vec<Named> {
+        self.usages.get_or_insert_with(Vec::new)
+    }
+
+    /// Return the values mutably, creating an empty list if there is none.
+    #[must_use]
+    pub fn mut_values(&mut self) -> &mut Vec<Named> {
+        self.values.get_or_insert_with(Vec::new)
+    }
+
     pub fn set_name(&mut self, name: &str) {
         self.name = name.to_string();
     }
 
-    pub fn set_nominal(&mut self, nominal: Option<u8>) {
+    pub fn set_nominal(&mut self, nominal: Option<u32>) {
         self.nominal = nominal;
     }
 
@@ -73,7 +174,7 @@ impl Type {
         self.restock = restock;
     }
 
-    pub fn set_min(&mut self, min: u8) {
+    pub fn set_min(&mut self, min: u32) {
         self.min = min;
     }
 
@@ -97,6 +198,10 @@ impl Type {
         self.category = category.cloned();
     }
 
+    pub fn set_tags(&mut self, tags: Option<&[Named]>) {
+        self.tags = tags.map(Vec::from);
+    }
+
     pub fn set_usages(&mut self, usages: Option<&[Named]>) {
         self.usages = usages.map(Vec::from);
     }
@@ -138,6 +243,10 @@ impl Display for Type {
             write!(f, "\ncategory:\t{}", category.name())?;
         }
 
+        if let Some(ref tags) = self.tags {
+            fmt_slice(f, "\ntags    :\t", tags)?;
+        }
+
         if let Some(ref usages) = self.usages {
             fmt_slice(f, "\nusages  :\t", usages)?;
         }
@@ -152,37 +261,6 @@ impl Display for Type {
 
 impl From<raw::Type> for Type {
     fn from(raw: raw::Type) -> Self {
-        Self {
-            name: raw.name,
-            nominal: raw.nominal.and_then(|s| s.parse::<u8>().ok()),
-            lifetime: raw
-                .lifetime
-                .and_then(|s| s.parse::<u32>().ok())
-                .unwrap_or(0),
-            restock: raw.restock.and_then(|s| s.parse::<u32>().ok()),
-            min: raw.min.and_then(|s| s.parse::<u8>().ok()).unwrap_or(0),
-            quantmin: raw.quantmin.and_then(|s| s.parse::<i64>().ok()),
-            quantmax: raw
-                .quantmax
-                .and_then(|s| s.parse::<i64>().ok())
-                .unwrap_or(0),
-            cost: raw.cost.and_then(|s| s.parse::<u32>().ok()),
-            flags: raw.flags.map_or_else(Flags::default, Flags::from),
-            category: raw
-                .category
-                .and_then(|category| category.name.map(Named::new)),
-            usages: raw.usages.map(|usages| {
-                usages
-                    .into_iter()
-                    .filter_map(|usage| usage.name.map(Named::new))
-                    .collect()
-            }),
-            values: raw.values.map(|values| {
-                values
-                    .into_iter()
-                    .filter_map(|value| value.name.map(Named::new))
-                    .collect()
-            }),
-        }
+        Self::recover(raw, None).0
     }
 }
@@ -0,0 +1,63 @@
+use crate::adjust::{Numeric, Operation, Outcome};
+use crate::Type;
+
+/// The quantity of types that do not hold a quantity of something.
+const NO_QUANTITY: i64 = -1;
+
+impl Type {
+    /// Apply a relative update to the given numeric field.
+    ///
+    /// Unset optional fields and quantities of -1, i.e. no quantity, are left as is.
+    /// Results exceeding the field's range are saturated.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the operation is invalid, see [`Operation::validate`].
+    pub fn adjust(
+        &mut self,
+        field: Numeric,
+        operation: Operation,
+    ) -> Result<Outcome, anyhow::Error> {
+        operation.validate()?;
+        let range = field.range();
+        let apply = |value: i64| operation.apply(value, range);
+
+        let (value, saturated) = match field {
+            Numeric::Nominal => match self.nominal {
+                Some(nominal) => apply(nominal.into()),
+                None => return Ok(Outcome::Unset),
+            },
+            Numeric::Lifetime => apply(self.lifetime.into()),
+            Numeric::Restock => match self.restock {
+                Some(restock) => apply(restock.into()),
+                None => return Ok(Outcome::Unset),
+            },
+            Numeric::Min => apply(self.min.into()),
+            Numeric::Quantmin => match self.quantmin {
+                Some(quantmin) if quantmin != NO_QUANTITY => apply(quantmin),
+                _ => return Ok(Outcome::Unset),
+            },
+            Numeric::Quantmax if self.quantmax == NO_QUANTITY => return Ok(Outcome::Unset),
+            Numeric::Quantmax => apply(self.quantmax),
+            Numeric::Cost => match self.cost {
+                Some(cost) => apply(cost.into()),
+                None => return Ok(Outcome::Unset),
+            },
+        }?;
+
+        match field {
+            Numeric::Nominal => self.nominal = u32::try_from(value).ok(),
+            Numeric::Lifetime => self.lifetime = u32::try_from(value).unwrap_or_default(),
+            Numeric::Restock => self.restock = u32::try_from(value).ok(),
+            Numeric::Min => self.min = u32::try_from(value).unwrap_or_default(),
+            Numeric::Quantmin => self.quantmin = Some(value),
+            Numeric::Quantmax => self.quantmax = value,
+            Numeric::Cost => self.cost = u32::try_from(value).ok(),
+        }
+
+        Ok(if saturated {
+            Outcome::Saturated
+        } else {
+            Outcome::Adjusted
+        })
+    }
+}
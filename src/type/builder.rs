@@ -0,0 +1,221 @@
+use crate::validate::{validate_type, Severity};
+use crate::{Flags, Named, Type};
+
+/// Lifetime of a new type in seconds, as commonly used for loot.
+pub const DEFAULT_LIFETIME: u32 = 14400;
+
+/// Cost of a new type, as used by all vanilla types.
+pub const DEFAULT_COST: u32 = 100;
+
+/// Builder for types with sensible defaults
+///
+/// New types have a nominal, min and restock of zero, quantmin and quantmax of -1
+/// (no quantity), a lifetime of [`DEFAULT_LIFETIME`], a cost of [`DEFAULT_COST`]
+/// and only `count_in_map` set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeBuilder {
+    typ: Type,
+}
+
+impl TypeBuilder {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        let mut flags = Flags::default();
+        flags.set_count_in_map(true);
+        Self {
+            typ: Type {
+                name: name.to_string(),
+                nominal: Some(0),
+                lifetime: DEFAULT_LIFETIME,
+                restock: Some(0),
+                min: 0,
+                quantmin: Some(-1),
+                quantmax: -1,
+                cost: Some(DEFAULT_COST),
+                flags,
+                category: None,
+                tags: None,
+                usages: None,
+                values: None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.typ.name = name.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn nominal(mut self, nominal: impl Into<Option<u32>>) -> Self {
+        self.typ.nominal = nominal.into();
+        self
+    }
+
+    #[must_use]
+    pub const fn lifetime(mut self, lifetime: u32) -> Self {
+        self.typ.lifetime = lifetime;
+        self
+    }
+
+    #[must_use]
+    pub fn restock(mut self, restock: impl Into<Option<u32>>) -> Self {
+        self.typ.restock = restock.into();
+        self
+    }
+
+    #[must_use]
+    pub const fn min(mut self, min: u32) -> Self {
+        self.typ.min = min;
+        self
+    }
+
+    #[must_use]
+    pub fn quantmin(mut self, quantmin: impl Into<Option<i64>>) -> Self {
+        self.typ.quantmin = quantmin.into();
+        self
+    }
+
+    #[must_use]
+    pub const fn quantmax(mut self, quantmax: i64) -> Self {
+        self.typ.quantmax = quantmax;
+        self
+    }
+
+    #[must_use]
+    pub fn cost(mut self, cost: impl Into<Option<u32>>) -> Self {
+        self.typ.cost = cost.into();
+        self
+    }
+
+    #[must_use]
+    pub const fn flags(mut self, flags: Flags) -> Self {
+        self.typ.flags = flags;
+        self
+    }
+
+    #[must_use]
+    pub fn count_in_cargo(mut self, count_in_cargo: bool) -> Self {
+        self.typ.flags.set_count_in_cargo(count_in_cargo);
+        self
+    }
+
+    #[must_use]
+    pub fn count_in_hoarder(mut self, count_in_hoarder: bool) -> Self {
+        self.typ.flags.set_count_in_hoarder(count_in_hoarder);
+        self
+    }
+
+    #[must_use]
+    pub fn count_in_map(mut self, count_in_map: bool) -> Self {
+        self.typ.flags.set_count_in_map(count_in_map);
+        self
+    }
+
+    #[must_use]
+    pub fn count_in_player(mut self, count_in_player: bool) -> Self {
+        self.typ.flags.set_count_in_player(count_in_player);
+        self
+    }
+
+    #[must_use]
+    pub fn crafted(mut self, crafted: bool) -> Self {
+        self.typ.flags.set_crafted(crafted);
+        self
+    }
+
+    #[must_use]
+    pub fn deloot(mut self, deloot: bool) -> Self {
+        self.typ.flags.set_deloot(deloot);
+        self
+    }
+
+    #[must_use]
+    pub fn category(mut self, category: Option<&str>) -> Self {
+        self.typ.category = category.map(|name| Named::new(name.to_string()));
+        self
+    }
+
+    /// Add a tag unless the type already has it.
+    #[must_use]
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.typ.add_tag(tag);
+        self
+    }
+
+    /// Add a usage unless the type already has it.
+    #[must_use]
+    pub fn usage(mut self, usage: &str) -> Self {
+        self.typ.add_usage(usage);
+        self
+    }
+
+    /// Add a value unless the type already has it.
+    #[must_use]
+    pub fn value(mut self, value: &str) -> Self {
+        self.typ.add_value(value);
+        self
+    }
+
+    /// Replace all tags.
+    #[must_use]
+    pub fn tags(mut self, tags: &[&str]) -> Self {
+        self.typ.tags = None;
+        tags.iter().fold(self, |builder, tag| builder.tag(tag))
+    }
+
+    /// Replace all usages.
+    #[must_use]
+    pub fn usages(mut self, usages: &[&str]) -> Self {
+        self.typ.usages = None;
+        usages
+            .iter()
+            .fold(self, |builder, usage| builder.usage(usage))
+    }
+
+    /// Replace all values.
+    #[must_use]
+    pub fn values(mut self, values: &[&str]) -> Self {
+        self.typ.values = None;
+        values
+            .iter()
+            .fold(self, |builder, value| builder.value(value))
+    }
+
+    /// Validate and return the type.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` listing the violated rules
+    /// if the name is empty or the type violates a rule of severity error.
+    pub fn build(self) -> Result<Type, anyhow::Error> {
+        if self.typ.name.is_empty() {
+            return Err(anyhow::Error::msg("type name must not be empty"));
+        }
+
+        let errors: Vec<String> = validate_type(&self.typ)
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity() == Severity::Error)
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+
+        if errors.is_empty() {
+            Ok(self.typ)
+        } else {
+            Err(anyhow::Error::msg(errors.join("\n")))
+        }
+    }
+}
+
+impl TypeBuilder {
+    /// Return the type without validating it.
+    pub(crate) fn unchecked(self) -> Type {
+        self.typ
+    }
+}
+
+impl From<Type> for TypeBuilder {
+    fn from(typ: Type) -> Self {
+        Self { typ }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::diff::Change;
+use crate::Type;
+
+impl Type {
+    /// Return the changes of all fields from `self` to `other`.
+    pub(crate) fn diff(&self, other: &Self) -> Vec<Change> {
+        let mut changes: Vec<Change> = [
+            Change::field("nominal", self.nominal, other.nominal),
+            Change::field("lifetime", Some(self.lifetime), Some(other.lifetime)),
+            Change::field("restock", self.restock, other.restock),
+            Change::field("min", Some(self.min), Some(other.min)),
+            Change::field("quantmin", self.quantmin, other.quantmin),
+            Change::field("quantmax", Some(self.quantmax), Some(other.quantmax)),
+            Change::field("cost", self.cost, other.cost),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        changes.extend(self.flags.diff(&other.flags));
+        changes.extend(
+            [
+                Change::field(
+                    "category",
+                    self.category.as_ref().map(|category| category.name()),
+                    other.category.as_ref().map(|category| category.name()),
+                ),
+                Change::list("tag", self.tags.as_deref(), other.tags.as_deref()),
+                Change::list("usage", self.usages.as_deref(), other.usages.as_deref()),
+                Change::list("value", self.values.as_deref(), other.values.as_deref()),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+        changes
+    }
+}
@@ -0,0 +1,222 @@
+use crate::document::{Element, Entry, Node};
+use crate::recovery::Recovery;
+use crate::{raw, Type};
+use serde_rw::ToXml;
+
+/// Child elements of a `<type>` in their canonical order.
+const FIELDS: [&str; 12] = [
+    "nominal", "lifetime", "restock", "min", "quantmin", "quantmax", "cost", "flags", "category",
+    "tag", "usage", "value",
+];
+
+impl Type {
+    /// Parse a type from a standalone `<type>` element strictly.
+    pub(crate) fn from_xml(text: &str) -> Result<Self, anyhow::Error> {
+        let element: Element = text.parse()?;
+
+        if element.name() != "type" {
+            return Err(anyhow::Error::msg(format!(
+                "expected <type>, found <{}>",
+                element.name()
+            )));
+        }
+
+        Self::from_element(&element)
+    }
+
+    /// Parse a type from its XML element strictly.
+    pub(crate) fn from_element(element: &Element) -> Result<Self, anyhow::Error> {
+        Ok(quick_xml::de::from_str::<Self>(&element.to_string())?)
+    }
+
+    /// Parse a type from its XML element gracefully, reporting the values that could not be read as is.
+    pub(crate) fn recover_element(
+        element: &Element,
+    ) -> Result<(Self, Vec<Recovery>), anyhow::Error> {
+        let raw = quick_xml::de::from_str::<raw::Type>(&element.to_string())?;
+        Ok(Self::recover(raw, Some(element)))
+    }
+
+    /// Render the type as a new XML element with the given indentation.
+    pub(crate) fn to_element(
+        &self,
+        indent: &str,
+        child_indent: &str,
+    ) -> Result<Element, anyhow::Error> {
+        let mut element: Element = self.to_xml()?.parse()?;
+        let children: Vec<Node> = std::mem::take(element.children_mut())
+            .into_iter()
+            .filter(|node| node.as_whitespace().is_none())
+            .collect();
+
+        if !children.is_empty() {
+            for child in children {
+                element
+                    .children_mut()
+                    .push(Node::Text(child_indent.to_string()));
+                element.children_mut().push(child);
+            }
+
+            element.children_mut().push(Node::Text(indent.to_string()));
+        }
+
+        Ok(element)
+    }
+
+    /// Apply the changes from `original` to `self` onto the element `original` was read from.
+    ///
+    /// Fields that did not change are left untouched, as are unknown child elements,
+    /// comments and attributes.
+    pub(crate) fn update_element(
+        &self,
+        original: &Self,
+        element: &mut Element,
+        indent: &str,
+        child_indent: &str,
+    ) -> Result<(), anyhow::Error> {
+        if self == original {
+            return Ok(());
+        }
+
+        if self.name != original.name {
+            element.set_attribute("name", &self.name);
+        }
+
+        let old: Element = original.to_xml()?.parse()?;
+        let new: Element = self.to_xml()?.parse()?;
+        let child_indent = element.child_indent().unwrap_or(child_indent).to_string();
+
+        for field in FIELDS {
+            let old_children: Vec<String> = named(&old, field).map(ToString::to_string).collect();
+            let new_children: Vec<&Element> = named(&new, field).collect();
+
+            if old_children.iter().eq(new_children
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .iter())
+            {
+                continue;
+            }
+
+            replace_field(element, field, &new_children, &child_indent);
+        }
+
+        if element
+            .children()
+            .last()
+            .is_some_and(|node| node.as_whitespace().is_none())
+        {
+            element.children_mut().push(Node::Text(indent.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Render the given fields onto the element regardless of their current content.
+    pub(crate) fn rewrite_fields(
+        &self,
+        element: &mut Element,
+        fields: &[&str],
+        child_indent: &str,
+    ) -> Result<(), anyhow::Error> {
+        let new: Element = self.to_xml()?.parse()?;
+        let child_indent = element.child_indent().unwrap_or(child_indent).to_string();
+
+        for field in FIELDS.into_iter().filter(|field| fields.contains(field)) {
+            let new_children: Vec<&Element> = named(&new, field).collect();
+            replace_field(element, field, &new_children, &child_indent);
+        }
+
+        Ok(())
+    }
+}
+
+impl Entry for Type {
+    const ROOT: &'static str = "types";
+    const ELEMENT: &'static str = "type";
+
+    fn key(&self) -> &str {
+        self.get_name()
+    }
+
+    fn set_key(&mut self, key: &str) {
+        self.set_name(key);
+    }
+}
+
+/// Replace the elements of the given field with the new ones.
+///
+/// Flags are updated attribute by attribute to keep unknown attributes.
+fn replace_field(
+    element: &mut Element,
+    field: &str,
+    new_children: &[&Element],
+    child_indent: &str,
+) {
+    if let ([flags], Some(existing)) = (new_children, element.element_mut(field)) {
+        if field == "flags" {
+            for name in flags.attribute_names() {
+                existing.set_attribute(name, &flags.attribute(name).unwrap_or_default());
+            }
+
+            return;
+        }
+    }
+
+    let index = element
+        .remove_elements(field)
+        .unwrap_or_else(|| insertion_index(element, field));
+    let nodes: Vec<Node> = new_children
+        .iter()
+        .flat_map(|&child| {
+            let mut child = child.clone();
+            child.clear_spans();
+            [Node::Text(child_indent.to_string()), Node::Element(child)]
+        })
+        .collect();
+    element.children_mut().splice(index..index, nodes);
+}
+
+fn named<'element>(
+    element: &'element Element,
+    name: &'element str,
+) -> impl Iterator<Item = &'element Element> {
+    element.elements().filter(move |child| child.name() == name)
+}
+
+/// Find the index at which to insert a yet missing field to keep the canonical order.
+fn insertion_index(element: &Element, field: &str) -> usize {
+    let rank = |node: &Node| {
+        node.as_element()
+            .and_then(|child| FIELDS.iter().position(|name| *name == child.name()))
+    };
+    let position = FIELDS
+        .iter()
+        .position(|name| *name == field)
+        .unwrap_or(FIELDS.len());
+    let children = element.children();
+
+    if let Some(index) = children
+        .iter()
+        .rposition(|node| rank(node).is_some_and(|rank| rank < position))
+    {
+        return index + 1;
+    }
+
+    if let Some(index) = children
+        .iter()
+        .position(|node| rank(node).is_some_and(|rank| rank > position))
+    {
+        return if index > 0 && children[index - 1].as_whitespace().is_some() {
+            index - 1
+        } else {
+            index
+        };
+    }
+
+    match children.last() {
+        Some(node) if node.as_whitespace().is_some() => children.len() - 1,
+        _ => children.len(),
+    }
+}
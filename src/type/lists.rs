@@ -0,0 +1,99 @@
+use crate::{Named, Type};
+
+impl Type {
+    /// Return whether the type has the given tag.
+    ///
+    /// Names are compared case-insensitively, like the game does.
+    #[must_use]
+    pub fn has_tag(&self, name: &str) -> bool {
+        contains(self.tags.as_deref(), name)
+    }
+
+    /// Return whether the type has the given usage.
+    ///
+    /// Names are compared case-insensitively, like the game does.
+    #[must_use]
+    pub fn has_usage(&self, name: &str) -> bool {
+        contains(self.usages.as_deref(), name)
+    }
+
+    /// Return whether the type has the given value.
+    ///
+    /// Names are compared case-insensitively, like the game does.
+    #[must_use]
+    pub fn has_value(&self, name: &str) -> bool {
+        contains(self.values.as_deref(), name)
+    }
+
+    /// Add the given tag unless the type already has it.
+    ///
+    /// Returns `true` if the tag was added.
+    pub fn add_tag(&mut self, name: &str) -> bool {
+        add(&mut self.tags, name)
+    }
+
+    /// Add the given usage unless the type already has it.
+    ///
+    /// Returns `true` if the usage was added.
+    pub fn add_usage(&mut self, name: &str) -> bool {
+        add(&mut self.usages, name)
+    }
+
+    /// Add the given value unless the type already has it.
+    ///
+    /// Returns `true` if the value was added.
+    pub fn add_value(&mut self, name: &str) -> bool {
+        add(&mut self.values, name)
+    }
+
+    /// Remove the given tag, unsetting the tags if none are left.
+    ///
+    /// Returns the removed tag, if any.
+    pub fn remove_tag(&mut self, name: &str) -> Option<Named> {
+        remove(&mut self.tags, name)
+    }
+
+    /// Remove the given usage, unsetting the usages if none are left.
+    ///
+    /// Returns the removed usage, if any.
+    pub fn remove_usage(&mut self, name: &str) -> Option<Named> {
+        remove(&mut self.usages, name)
+    }
+
+    /// Remove the given value, unsetting the values if none are left.
+    ///
+    /// Returns the removed value, if any.
+    pub fn remove_value(&mut self, name: &str) -> Option<Named> {
+        remove(&mut self.values, name)
+    }
+}
+
+fn contains(list: Option<&[Named]>, name: &str) -> bool {
+    list.unwrap_or_default()
+        .iter()
+        .any(|named| named.to_string().eq_ignore_ascii_case(name))
+}
+
+fn add(list: &mut Option<Vec<Named>>, name: &str) -> bool {
+    if contains(list.as_deref(), name) {
+        return false;
+    }
+
+    list.get_or_insert_with(Vec::new)
+        .push(Named::from_text(name));
+    true
+}
+
+fn remove(list: &mut Option<Vec<Named>>, name: &str) -> Option<Named> {
+    let names = list.as_mut()?;
+    let index = names
+        .iter()
+        .position(|named| named.to_string().eq_ignore_ascii_case(name))?;
+    let removed = names.remove(index);
+
+    if names.is_empty() {
+        *list = None;
+    }
+
+    Some(removed)
+}
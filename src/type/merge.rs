@@ -0,0 +1,74 @@
+use crate::merge::{Conflict, Merger};
+use crate::Type;
+
+impl Type {
+    /// Merge their changes to the common `base` into ours.
+    ///
+    /// If there is no common base, i.e. both sides added the type, every differing field conflicts.
+    pub(crate) fn merge3(&self, base: Option<&Self>, theirs: &Self) -> (Self, Vec<Conflict>) {
+        let mut merger = Merger::new(&self.name);
+        let typ = Self {
+            name: self.name.clone(),
+            nominal: merger.merge(
+                "nominal",
+                base.map(|base| &base.nominal),
+                &self.nominal,
+                &theirs.nominal,
+            ),
+            lifetime: merger.merge(
+                "lifetime",
+                base.map(|base| &base.lifetime),
+                &self.lifetime,
+                &theirs.lifetime,
+            ),
+            restock: merger.merge(
+                "restock",
+                base.map(|base| &base.restock),
+                &self.restock,
+                &theirs.restock,
+            ),
+            min: merger.merge("min", base.map(|base| &base.min), &self.min, &theirs.min),
+            quantmin: merger.merge(
+                "quantmin",
+                base.map(|base| &base.quantmin),
+                &self.quantmin,
+                &theirs.quantmin,
+            ),
+            quantmax: merger.merge(
+                "quantmax",
+                base.map(|base| &base.quantmax),
+                &self.quantmax,
+                &theirs.quantmax,
+            ),
+            cost: merger.merge(
+                "cost",
+                base.map(|base| &base.cost),
+                &self.cost,
+                &theirs.cost,
+            ),
+            flags: self
+                .flags
+                .merge3(base.map(|base| &base.flags), &theirs.flags, &mut merger),
+            category: merger.merge(
+                "category",
+                base.map(|base| &base.category),
+                &self.category,
+                &theirs.category,
+            ),
+            tags: merger.merge("tag", base.map(|base| &base.tags), &self.tags, &theirs.tags),
+            usages: merger.merge(
+                "usage",
+                base.map(|base| &base.usages),
+                &self.usages,
+                &theirs.usages,
+            ),
+            values: merger.merge(
+                "value",
+                base.map(|base| &base.values),
+                &self.values,
+                &theirs.values,
+            ),
+        };
+        (typ, merger.into_conflicts())
+    }
+}
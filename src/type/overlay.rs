@@ -0,0 +1,89 @@
+use crate::{Flags, Type};
+
+impl Type {
+    /// Override the fields of `self` with those present in `other`.
+    ///
+    /// `fields` names the child elements present in the element `other` was read from,
+    /// with flags listed per attribute, e.g. `flags.count_in_map`.
+    /// If it is unknown, mandatory fields count as always present and
+    /// optional fields as present if they are set.
+    #[must_use]
+    pub(crate) fn overlay(&self, other: &Self, fields: Option<&[String]>) -> Self {
+        let present = |field: &str| fields.is_none_or(|fields| contains(fields, field));
+        Self {
+            name: self.name.clone(),
+            nominal: pick(present("nominal"), other.nominal, self.nominal),
+            lifetime: if present("lifetime") {
+                other.lifetime
+            } else {
+                self.lifetime
+            },
+            restock: pick(present("restock"), other.restock, self.restock),
+            min: if present("min") { other.min } else { self.min },
+            quantmin: pick(present("quantmin"), other.quantmin, self.quantmin),
+            quantmax: if present("quantmax") {
+                other.quantmax
+            } else {
+                self.quantmax
+            },
+            cost: pick(present("cost"), other.cost, self.cost),
+            flags: fields.map_or_else(
+                || other.flags.clone(),
+                |fields| overlay_flags(&self.flags, &other.flags, fields),
+            ),
+            category: pick(
+                present("category"),
+                other.category.clone(),
+                self.category.clone(),
+            ),
+            tags: pick(present("tag"), other.tags.clone(), self.tags.clone()),
+            usages: pick(present("usage"), other.usages.clone(), self.usages.clone()),
+            values: pick(present("value"), other.values.clone(), self.values.clone()),
+        }
+    }
+}
+
+/// Take `other` if the field is present and set, and `current` otherwise.
+fn pick<T>(present: bool, other: Option<T>, current: Option<T>) -> Option<T> {
+    if present {
+        other.or(current)
+    } else {
+        current
+    }
+}
+
+fn contains(fields: &[String], field: &str) -> bool {
+    fields.iter().any(|present| present == field)
+}
+
+/// Override the flags of `current` with the attributes present in `other`.
+fn overlay_flags(current: &Flags, other: &Flags, fields: &[String]) -> Flags {
+    let mut flags = current.clone();
+    let present = |attribute: &str| contains(fields, &format!("flags.{attribute}"));
+
+    if present("count_in_cargo") {
+        flags.set_count_in_cargo(other.get_count_in_cargo());
+    }
+
+    if present("count_in_hoarder") {
+        flags.set_count_in_hoarder(other.get_count_in_hoarder());
+    }
+
+    if present("count_in_map") {
+        flags.set_count_in_map(other.get_count_in_map());
+    }
+
+    if present("count_in_player") {
+        flags.set_count_in_player(other.get_count_in_player());
+    }
+
+    if present("crafted") {
+        flags.set_crafted(other.get_crafted());
+    }
+
+    if present("deloot") {
+        flags.set_deloot(other.get_deloot());
+    }
+
+    flags
+}
@@ -0,0 +1,263 @@
+use crate::document::{Element, Position};
+use crate::recovery::{Kind, Recovery};
+use crate::util::parse_bool_or_false;
+use crate::{raw, Flags, Named, Type};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The largest magnitude a float may have to be converted into an integer losslessly.
+const MAX_EXACT_FLOAT: f64 = 9_007_199_254_740_992.0;
+
+impl Type {
+    /// Convert a raw type, reporting every value that could not be read as is.
+    ///
+    /// The `element` the raw type was read from, if any, is used to locate the values.
+    pub(crate) fn recover(raw: raw::Type, element: Option<&Element>) -> (Self, Vec<Recovery>) {
+        let mut recoverer = Recoverer {
+            name: &raw.name,
+            element,
+            recoveries: Vec::new(),
+        };
+        let typ = Self {
+            name: raw.name.clone(),
+            nominal: recoverer.optional("nominal", raw.nominal),
+            lifetime: recoverer.mandatory("lifetime", raw.lifetime),
+            restock: recoverer.optional("restock", raw.restock),
+            min: recoverer.mandatory("min", raw.min),
+            quantmin: recoverer.optional("quantmin", raw.quantmin),
+            quantmax: recoverer.mandatory("quantmax", raw.quantmax),
+            cost: recoverer.optional("cost", raw.cost),
+            flags: recoverer.flags(raw.flags),
+            category: raw.category.and_then(|category| {
+                recoverer
+                    .names("category", vec![category])
+                    .into_iter()
+                    .next()
+            }),
+            tags: raw.tags.map(|tags| recoverer.names("tag", tags)),
+            usages: raw.usages.map(|usages| recoverer.names("usage", usages)),
+            values: raw.values.map(|values| recoverer.names("value", values)),
+        };
+        (typ, recoverer.recoveries)
+    }
+}
+
+struct Recoverer<'raw> {
+    name: &'raw str,
+    element: Option<&'raw Element>,
+    recoveries: Vec<Recovery>,
+}
+
+impl Recoverer<'_> {
+    fn optional<T>(&mut self, field: &str, text: Option<String>) -> Option<T>
+    where
+        T: Display + FromStr + TryFrom<i64>,
+    {
+        let text = text?;
+
+        match parse(&text) {
+            Some((value, true)) => Some(value),
+            Some((value, false)) => {
+                self.report(
+                    Kind::Recovered,
+                    field,
+                    Some(&text),
+                    format!("read {text:?} as {value}"),
+                );
+                Some(value)
+            }
+            None => {
+                self.report(
+                    Kind::Lost,
+                    field,
+                    Some(&text),
+                    format!("discarded invalid value {text:?}"),
+                );
+                None
+            }
+        }
+    }
+
+    fn mandatory<T>(&mut self, field: &str, text: Option<String>) -> T
+    where
+        T: Default + Display + FromStr + TryFrom<i64>,
+    {
+        let Some(text) = text else {
+            self.report(
+                Kind::Defaulted,
+                field,
+                None,
+                format!("missing, using {}", T::default()),
+            );
+            return T::default();
+        };
+
+        match parse(&text) {
+            Some((value, true)) => value,
+            Some((value, false)) => {
+                self.report(
+                    Kind::Recovered,
+                    field,
+                    Some(&text),
+                    format!("read {text:?} as {value}"),
+                );
+                value
+            }
+            None => {
+                self.report(
+                    Kind::Lost,
+                    field,
+                    Some(&text),
+                    format!("replaced invalid value {text:?} with {}", T::default()),
+                );
+                T::default()
+            }
+        }
+    }
+
+    fn flags(&mut self, flags: Option<raw::Flags>) -> Flags {
+        let Some(raw) = flags else {
+            self.report(
+                Kind::Defaulted,
+                "flags",
+                None,
+                "missing, using 0 for all flags".to_string(),
+            );
+            return Flags::default();
+        };
+
+        let mut flags = Flags::default();
+        flags.set_count_in_cargo(self.flag("count_in_cargo", raw.count_in_cargo));
+        flags.set_count_in_hoarder(self.flag("count_in_hoarder", raw.count_in_hoarder));
+        flags.set_count_in_map(self.flag("count_in_map", raw.count_in_map));
+        flags.set_count_in_player(self.flag("count_in_player", raw.count_in_player));
+        flags.set_crafted(self.flag("crafted", raw.crafted));
+        flags.set_deloot(self.flag("deloot", raw.deloot));
+        flags
+    }
+
+    fn flag(&mut self, name: &str, text: Option<String>) -> bool {
+        let field = format!("flags.{name}");
+        let Some(text) = text else {
+            self.report(
+                Kind::Defaulted,
+                &field,
+                None,
+                "missing, using 0".to_string(),
+            );
+            return false;
+        };
+
+        if is_bool(&text) {
+            parse_bool_or_false(&text)
+        } else if is_bool(text.trim()) {
+            let value = parse_bool_or_false(text.trim());
+            self.report(
+                Kind::Recovered,
+                &field,
+                Some(&text),
+                format!("read {text:?} as {}", u8::from(value)),
+            );
+            value
+        } else {
+            self.report(
+                Kind::Lost,
+                &field,
+                Some(&text),
+                format!("replaced invalid value {text:?} with 0"),
+            );
+            false
+        }
+    }
+
+    fn names(&mut self, field: &str, names: Vec<raw::Named>) -> Vec<Named> {
+        let mut nameless = self
+            .element
+            .into_iter()
+            .flat_map(Element::elements)
+            .filter(|element| {
+                element.name() == field
+                    && element.attribute("name").is_none()
+                    && element.attribute("user").is_none()
+            });
+        let mut result = Vec::with_capacity(names.len());
+
+        for named in names {
+            if let Some(name) = named.name {
+                result.push(Named::new(name));
+            } else if let Some(user) = named.user {
+                result.push(Named::user(user));
+            } else {
+                let element = nameless.next();
+                let original = element.map(ToString::to_string);
+                self.recoveries.push(
+                    Recovery::new(
+                        Kind::Lost,
+                        Some(self.name),
+                        Some(field),
+                        format!("dropped <{field}> without name"),
+                    )
+                    .with_original(original.as_deref())
+                    .with_position(
+                        element
+                            .and_then(Element::position)
+                            .or_else(|| self.position(field)),
+                    ),
+                );
+            }
+        }
+
+        result
+    }
+
+    fn report(&mut self, kind: Kind, field: &str, original: Option<&str>, message: String) {
+        let position = self.position(field.split('.').next().unwrap_or(field));
+        self.recoveries.push(
+            Recovery::new(kind, Some(self.name), Some(field), message)
+                .with_original(original)
+                .with_position(position),
+        );
+    }
+
+    /// Locate the given field, falling back to the type's position.
+    fn position(&self, field: &str) -> Option<Position> {
+        self.element.and_then(|element| {
+            element
+                .element(field)
+                .and_then(Element::position)
+                .or_else(|| element.position())
+        })
+    }
+}
+
+/// Parse a number, returning whether the text was a canonical representation of it.
+///
+/// Surrounding whitespace is ignored and integral floats such as `1e4` are accepted.
+fn parse<T>(text: &str) -> Option<(T, bool)>
+where
+    T: FromStr + TryFrom<i64>,
+{
+    if let Ok(value) = text.parse() {
+        return Some((value, true));
+    }
+
+    let trimmed = text.trim();
+
+    if let Ok(value) = trimmed.parse() {
+        return Some((value, false));
+    }
+
+    trimmed
+        .parse::<f64>()
+        .ok()
+        .filter(|float| float.fract() == 0.0 && float.abs() <= MAX_EXACT_FLOAT)
+        .and_then(|float| {
+            #[allow(clippy::cast_possible_truncation)]
+            T::try_from(float as i64).ok()
+        })
+        .map(|value| (value, false))
+}
+
+fn is_bool(text: &str) -> bool {
+    text.parse::<bool>().is_ok() || text.parse::<u8>().is_ok()
+}
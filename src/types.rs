@@ -1,57 +1,626 @@
+mod case_sensitivity;
+mod index;
+mod location;
+mod source;
+mod type_mut;
+
+use crate::dedup::{Duplicate, Policy};
+use crate::diff::{TypeDiff, TypesDiff};
+use crate::document::Document;
+use crate::filter::Filter;
+use crate::merge::{Conflict, Order, Strategy, ThreeWayMerge, TwoWayMerge};
+use crate::recovery::{Kind, Recovery};
 use crate::{raw, Type};
-use serde::ser::Error;
+use index::Index;
 use serde::{Deserialize, Serialize};
 use serde_rw::{FromFile, ToXml};
+use source::Source;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use std::ops::Add;
 use std::slice::{Iter, IterMut};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+pub use case_sensitivity::CaseSensitivity;
+pub use location::Location;
+pub use type_mut::TypeMut;
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(rename = "types")]
 pub struct Types {
     #[serde(rename = "type")]
     types: Vec<Type>,
+    #[serde(skip)]
+    source: Option<Source>,
+    #[serde(skip)]
+    case: CaseSensitivity,
+    #[serde(skip)]
+    index: OnceLock<Index>,
+    #[serde(skip)]
+    stale: bool,
 }
 
 impl Types {
+    /// Parse the types.xml strictly
+    ///
+    /// The original document is kept, so that comments, unknown elements and
+    /// formatting survive writing the types back.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the file is not well-formed or a type cannot be deserialized.
+    pub fn read(filename: &str) -> Result<Self, anyhow::Error> {
+        let (source, types) = Source::parse(Document::read(filename)?)?;
+        Ok(Self::new(types, Some(source)))
+    }
+
     /// Parse the types.xml gracefully
     ///
     /// This corrects common formatting errors but may also lead to data loss.
+    /// If the file is not well-formed XML, its comments and formatting are not preserved.
+    /// Use [`Types::read_gracefully_with_recoveries`] to learn why.
     ///
     /// # Arguments
     /// * `filename` - The path to the file to read.
     ///
     /// # Errors
-    /// Returns a `serde::rw::Error` if the deserialization fails.
+    /// Returns an `anyhow::Error` holding both the document and the deserialization error
+    /// if the deserialization fails.
     pub fn read_gracefully(filename: &str) -> Result<Self, anyhow::Error> {
-        raw::Types::from_file(filename).map(Self::from)
+        Self::read_gracefully_with_recoveries(filename).map(|(types, _)| types)
+    }
+
+    /// Parse the types.xml gracefully and report the values that could not be read as is
+    ///
+    /// See [`Types::read_gracefully`].
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to read.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if the deserialization fails.
+    pub fn read_gracefully_with_recoveries(
+        filename: &str,
+    ) -> Result<(Self, Vec<Recovery>), anyhow::Error> {
+        match Document::read(filename).and_then(Source::recover) {
+            Ok((source, types, recoveries)) => Ok((Self::new(types, Some(source)), recoveries)),
+            Err(error) => {
+                let raw = raw::Types::from_file(filename)
+                    .map_err(|raw_error| anyhow::Error::msg(format!("{error}\n{raw_error}")))?;
+                let mut recoveries = vec![Recovery::new(
+                    Kind::Lost,
+                    None,
+                    None,
+                    format!("{error}; comments and formatting are not preserved"),
+                )];
+                let types = raw
+                    .types
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|raw| {
+                        let (typ, mut type_recoveries) = Type::recover(raw, None);
+                        recoveries.append(&mut type_recoveries);
+                        typ
+                    })
+                    .collect();
+                Ok((Self::new(types, None), recoveries))
+            }
+        }
     }
 
-    pub fn types(&self) -> Iter<Type> {
+    /// Write the types.xml to the given file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the file to write.
+    ///
+    /// # Errors
+    /// Returns an `anyhow::Error` if serialization or writing the file fails.
+    pub fn write(&self, filename: &str) -> Result<(), anyhow::Error> {
+        Ok(std::fs::write(filename, self.render()?)?)
+    }
+
+    pub fn types(&self) -> Iter<'_, Type> {
         self.types.iter()
     }
 
+    /// Iterate over all types mutably.
+    ///
+    /// The name index catches up with renamed types on the next change, until then lookups scan the types.
     pub fn mut_types(&mut self) -> IterMut<'_, Type> {
+        self.stale = true;
         self.types.iter_mut()
     }
 
+    /// Iterate over all types matching the given filter.
+    pub fn matching<'filter>(
+        &'filter self,
+        filter: &'filter Filter,
+    ) -> impl Iterator<Item = &'filter Type> {
+        self.types.iter().filter(|typ| filter.matches(typ))
+    }
+
+    #[must_use]
+    pub const fn case_sensitivity(&self) -> CaseSensitivity {
+        self.case
+    }
+
+    /// Set how names are compared when looking types up by name.
+    pub fn set_case_sensitivity(&mut self, case: CaseSensitivity) {
+        if case != self.case {
+            self.case = case;
+            self.index = OnceLock::new();
+            self.stale = false;
+        }
+    }
+
+    /// Return the first type with the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Type> {
+        self.position(name).map(|position| &self.types[position])
+    }
+
+    /// Return the first type with the given name mutably.
+    pub fn get_mut(&mut self, name: &str) -> Option<TypeMut<'_>> {
+        self.refresh();
+        let position = self.position(name)?;
+        Some(TypeMut::new(
+            &mut self.types[position],
+            position,
+            &mut self.index,
+            self.case,
+        ))
+    }
+
+    /// Return whether there is a type with the given name.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    /// Return the positions of all types with the given name in document order.
+    #[must_use]
+    pub fn positions(&self, name: &str) -> Vec<usize> {
+        let key = self.case.key(name);
+
+        if self.stale {
+            return self
+                .types
+                .iter()
+                .enumerate()
+                .filter(|(_, typ)| self.case.key(typ.get_name()) == key)
+                .map(|(position, _)| position)
+                .collect();
+        }
+
+        self.index().all(&key).to_vec()
+    }
+
+    /// Add the type unless there already is a type with the same name.
     pub fn add(&mut self, typ: Type) {
-        if !self
+        self.refresh();
+
+        if !self.contains(typ.get_name()) {
+            self.push(typ);
+        }
+    }
+
+    /// Replace the type with the same name or add it if there is none.
+    ///
+    /// Returns the replaced type.
+    pub fn insert(&mut self, typ: Type) -> Option<Type> {
+        self.refresh();
+
+        if let Some(position) = self.position(typ.get_name()) {
+            Some(std::mem::replace(&mut self.types[position], typ))
+        } else {
+            self.push(typ);
+            None
+        }
+    }
+
+    /// Remove the first type with the given name.
+    ///
+    /// Returns the removed type.
+    pub fn remove(&mut self, name: &str) -> Option<Type> {
+        self.refresh();
+        let position = self.position(name)?;
+        let typ = self.types.remove(position);
+
+        if let Some(index) = self.index.get_mut() {
+            index.remove(&self.case.key(typ.get_name()), position);
+        }
+
+        Some(typ)
+    }
+
+    /// Remove all types not satisfying the predicate.
+    ///
+    /// Returns the removed types.
+    pub fn retain<F>(&mut self, mut predicate: F) -> Vec<Type>
+    where
+        F: FnMut(&Type) -> bool,
+    {
+        self.refresh();
+        let removed: Vec<bool> = self.types.iter().map(|typ| !predicate(typ)).collect();
+
+        if let Some(index) = self.index.get_mut() {
+            index.retain(&removed);
+        }
+
+        let mut flags = removed.into_iter();
+        let (kept, removed) = std::mem::take(&mut self.types)
+            .into_iter()
+            .partition(|_| flags.next() == Some(false));
+        self.types = kept;
+        removed
+    }
+
+    /// Find all types defined more than once.
+    ///
+    /// # Arguments
+    /// * `case` - Whether names differing only in case are duplicates.
+    #[must_use]
+    pub fn duplicates(&self, case: CaseSensitivity) -> Vec<Duplicate> {
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut by_key: HashMap<_, usize> = HashMap::new();
+
+        for (position, typ) in self.types.iter().enumerate() {
+            let group = *by_key.entry(case.key(typ.get_name())).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(position);
+        }
+
+        let present = self.present_fields();
+        groups
+            .into_iter()
+            .filter(|positions| positions.len() > 1)
+            .map(|positions| {
+                let copies = positions
+                    .iter()
+                    .map(|&position| self.types[position].clone())
+                    .collect();
+                let fields = present.map(|present| {
+                    positions
+                        .iter()
+                        .map(|&position| present[position].clone())
+                        .collect()
+                });
+                Duplicate::new(positions, copies, fields)
+            })
+            .collect()
+    }
+
+    /// Resolve all types defined more than once into a single type each.
+    ///
+    /// Returns the resolved duplicates.
+    ///
+    /// # Arguments
+    /// * `policy` - How to resolve the copies.
+    /// * `case` - Whether names differing only in case are duplicates.
+    pub fn dedup(&mut self, policy: Policy, case: CaseSensitivity) -> Vec<Duplicate> {
+        let duplicates = self.duplicates(case);
+        let mut removed = vec![false; self.types.len()];
+
+        for duplicate in &duplicates {
+            let kept = duplicate.kept_position(policy);
+
+            if let Some(typ) = duplicate.resolve(policy) {
+                self.types[kept] = typ;
+            }
+
+            for &position in duplicate.positions() {
+                removed[position] = position != kept;
+            }
+        }
+
+        // The kept copies may take the name of another copy, differing in case.
+        self.stale = true;
+        self.refresh();
+
+        if let Some(index) = self.index.get_mut() {
+            index.retain(&removed);
+        }
+
+        let mut removed = removed.into_iter();
+        self.types.retain(|_| !removed.next().unwrap_or(false));
+        duplicates
+    }
+
+    /// Compare these types to `other`.
+    ///
+    /// Types are matched by their name according to the case sensitivity of these types.
+    /// Of types defined more than once, the last definition is compared, like the game uses it.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> TypesDiff {
+        let ours = self.by_name(self.case);
+        let theirs = other.by_name(self.case);
+        let key = |typ: &Type| self.case.key(typ.get_name()).into_owned();
+        TypesDiff::new(
+            effective(&other.types, &theirs, key)
+                .filter(|typ| !ours.contains_key(&key(typ)))
+                .cloned()
+                .collect(),
+            effective(&self.types, &ours, key)
+                .filter(|typ| !theirs.contains_key(&key(typ)))
+                .cloned()
+                .collect(),
+            effective(&other.types, &theirs, key)
+                .filter_map(|typ| ours.get(&key(typ)).and_then(|old| TypeDiff::new(old, typ)))
+                .collect(),
+        )
+    }
+
+    /// Merge the extension's types into these types.
+    ///
+    /// Types present on both sides are resolved according to `strategy`.
+    /// If the extension was read from a document, [`Strategy::Overlay`] only takes
+    /// the fields present in its elements, so it may be read gracefully from a partial file.
+    /// New types from such a file take the defaults of [`Type::builder`] for the absent fields.
+    /// Types defined more than once on either side are collapsed into their last definition,
+    /// like the game uses it, which takes the place of the first one.
+    #[must_use]
+    pub fn merge(&self, extension: &Self, strategy: Strategy, order: Order) -> TwoWayMerge {
+        let ours = self.by_name(self.case);
+        let key = |typ: &Type| self.case.key(typ.get_name()).into_owned();
+        let mut theirs: HashMap<String, usize> = HashMap::with_capacity(extension.types.len());
+
+        for (index, typ) in extension.types.iter().enumerate() {
+            theirs.insert(key(typ), index);
+        }
+
+        let fields = extension.present_fields();
+        let mut overridden = Vec::new();
+        let mut ignored = Vec::new();
+        let mut seen = HashSet::with_capacity(self.types.len());
+        let mut types: Vec<Type> = self
             .types
             .iter()
-            .any(|existing| existing.get_name() == typ.get_name())
+            .filter(|typ| seen.insert(key(typ)))
+            .map(|typ| ours[&key(typ)])
+            .map(|typ| match theirs.get(&key(typ)) {
+                Some(&index) if *typ != extension.types[index] => {
+                    let other = &extension.types[index];
+
+                    match strategy {
+                        Strategy::BaseWins => {
+                            ignored.push(typ.get_name().to_string());
+                            typ.clone()
+                        }
+                        Strategy::ExtensionWins => {
+                            overridden.push(typ.get_name().to_string());
+                            other.clone()
+                        }
+                        Strategy::Overlay => {
+                            let overlay =
+                                typ.overlay(other, fields.map(|fields| fields[index].as_slice()));
+
+                            if overlay != *typ {
+                                overridden.push(typ.get_name().to_string());
+                            }
+
+                            overlay
+                        }
+                    }
+                }
+                _ => typ.clone(),
+            })
+            .collect();
+        types.extend(
+            extension
+                .types
+                .iter()
+                .enumerate()
+                .filter(|(index, typ)| {
+                    !ours.contains_key(&key(typ)) && theirs.get(&key(typ)) == Some(index)
+                })
+                .map(|(index, typ)| match (strategy, fields) {
+                    (Strategy::Overlay, Some(fields)) => Type::builder(typ.get_name())
+                        .unchecked()
+                        .overlay(typ, Some(&fields[index])),
+                    _ => typ.clone(),
+                }),
+        );
+
+        if order == Order::Sort {
+            types.sort_by(|lhs, rhs| lhs.get_name().cmp(rhs.get_name()));
+        }
+
+        TwoWayMerge::new(self.with_types(types), overridden, ignored)
+    }
+
+    /// Merge the changes from `base` to `theirs` into these types.
+    ///
+    /// Upstream changes are applied to fields we did not change, while our changes are kept.
+    /// Fields both sides changed differently are reported as conflicts and keep our value.
+    /// Of types defined more than once upstream, the last definition is merged.
+    #[must_use]
+    pub fn merge3(&self, base: &Self, theirs: &Self) -> ThreeWayMerge {
+        let bases = base.by_name(self.case);
+        let ours = self.by_name(self.case);
+        let their = theirs.by_name(self.case);
+        let key = |typ: &Type| self.case.key(typ.get_name()).into_owned();
+        let mut types = Vec::with_capacity(self.types.len());
+        let mut conflicts = Vec::new();
+
+        for typ in &self.types {
+            match (bases.get(&key(typ)), their.get(&key(typ))) {
+                (base, Some(theirs)) => {
+                    let (typ, field_conflicts) = typ.merge3(base.copied(), theirs);
+                    types.push(typ);
+                    conflicts.extend(field_conflicts);
+                }
+                (Some(base), None) if typ == *base => {}
+                (Some(_), None) => {
+                    conflicts.push(Conflict::RemovedByThem {
+                        name: typ.get_name().to_string(),
+                    });
+                    types.push(typ.clone());
+                }
+                (None, None) => types.push(typ.clone()),
+            }
+        }
+
+        for typ in effective(&theirs.types, &their, key).filter(|typ| !ours.contains_key(&key(typ)))
         {
-            self.types.push(typ);
+            match bases.get(&key(typ)) {
+                Some(base) if typ == *base => {}
+                Some(_) => conflicts.push(Conflict::RemovedByUs {
+                    name: typ.get_name().to_string(),
+                }),
+                None => types.push(typ.clone()),
+            }
         }
+
+        ThreeWayMerge::new(self.with_types(types), conflicts)
     }
 
-    pub fn remove(&mut self, name: &str) -> Option<Type> {
-        if let Some(index) = self.types.iter().position(|typ| typ.get_name() == name) {
-            Some(self.types.remove(index))
-        } else {
-            None
+    /// Locate the first type with the given name in the document the types were read from.
+    ///
+    /// Names are compared according to the case sensitivity.
+    /// Returns `None` if the types were not read from a well-formed document
+    /// or the document contains no type of that name.
+    #[must_use]
+    pub fn locate(&self, name: &str) -> Option<Location<'_>> {
+        let source = self.source.as_ref()?;
+        source
+            .positions(name, self.case)
+            .first()
+            .and_then(|&position| source.element(position))
+            .map(Location::new)
+    }
+
+    /// Locate the type at the given position in the document the types were read from.
+    ///
+    /// Unlike [`Types::locate`], this finds the right element for each copy of a duplicate type,
+    /// as long as the types were not reordered since reading them.
+    #[must_use]
+    pub fn locate_at(&self, position: usize) -> Option<Location<'_>> {
+        let typ = self.types.get(position)?;
+        let source = self.source.as_ref()?;
+        source
+            .origin(position, typ.get_name(), self.case)
+            .and_then(|position| source.element(position))
+            .map(Location::new)
+    }
+
+    pub(crate) const fn new(types: Vec<Type>, source: Option<Source>) -> Self {
+        Self {
+            types,
+            source,
+            case: CaseSensitivity::Sensitive,
+            index: OnceLock::new(),
+            stale: false,
+        }
+    }
+
+    /// Return new types with the same source and case sensitivity as these.
+    fn with_types(&self, types: Vec<Type>) -> Self {
+        Self {
+            types,
+            source: self.source.clone(),
+            case: self.case,
+            index: OnceLock::new(),
+            stale: false,
         }
     }
+
+    /// Return the position of the first type with the given name.
+    fn position(&self, name: &str) -> Option<usize> {
+        let key = self.case.key(name);
+
+        if self.stale {
+            return self
+                .types
+                .iter()
+                .position(|typ| self.case.key(typ.get_name()) == key);
+        }
+
+        self.index().first(&key)
+    }
+
+    fn index(&self) -> &Index {
+        self.index
+            .get_or_init(|| Index::new(&self.types, self.case))
+    }
+
+    /// Bring the index up to date with types renamed through [`Types::mut_types`].
+    fn refresh(&mut self) {
+        if std::mem::take(&mut self.stale) {
+            if let Some(index) = self.index.get_mut() {
+                index.refresh(&self.types, self.case);
+            }
+        }
+    }
+
+    /// Append the type, keeping the index up to date.
+    fn push(&mut self, typ: Type) {
+        self.refresh();
+
+        if let Some(index) = self.index.get_mut() {
+            index.push(self.case.key(typ.get_name()).into_owned(), self.types.len());
+        }
+
+        self.types.push(typ);
+    }
+
+    /// Locate all types with the given name in the document the types were read from.
+    ///
+    /// Names are compared according to the case sensitivity.
+    pub fn locate_all<'types>(
+        &'types self,
+        name: &'types str,
+    ) -> impl Iterator<Item = Location<'types>> + 'types {
+        self.source.iter().flat_map(move |source| {
+            source
+                .positions(name, self.case)
+                .iter()
+                .filter_map(|&position| source.element(position))
+                .map(Location::new)
+        })
+    }
+
+    /// Return the fields present in the elements the types were read from.
+    ///
+    /// Returns `None` if the types were not read from a document or changed since.
+    fn present_fields(&self) -> Option<&[Vec<String>]> {
+        self.source
+            .as_ref()
+            .filter(|source| source.is_unchanged(&self.types))
+            .map(Source::fields)
+    }
+
+    /// Map the keys of the names according to `case` to their types, keeping the last one of duplicates.
+    fn by_name(&self, case: CaseSensitivity) -> HashMap<String, &Type> {
+        self.types
+            .iter()
+            .map(|typ| (case.key(typ.get_name()).into_owned(), typ))
+            .collect()
+    }
+
+    fn render(&self) -> Result<String, anyhow::Error> {
+        self.source.as_ref().map_or_else(
+            || self.to_xml_pretty(' ', 4),
+            |source| Ok(source.render(&self.types)?.to_string()),
+        )
+    }
+}
+
+/// Iterate over the given types that are in effect according to `by_name`.
+fn effective<'types>(
+    types: &'types [Type],
+    by_name: &'types HashMap<String, &'types Type>,
+    key: impl Fn(&Type) -> String,
+) -> impl Iterator<Item = &'types Type> {
+    types.iter().filter(move |typ| {
+        by_name
+            .get(&key(typ))
+            .is_some_and(|effective| std::ptr::eq(*effective, *typ))
+    })
 }
 
 impl Add for Types {
@@ -62,27 +631,163 @@ impl Add for Types {
         types.extend(self.types);
         types.sort_by(|lhs, rhs| lhs.get_name().cmp(rhs.get_name()));
         types.dedup_by(|lhs, rhs| lhs.get_name().eq(rhs.get_name()));
-        Self { types }
+        Self {
+            types,
+            source: self.source,
+            case: self.case,
+            index: OnceLock::new(),
+            stale: false,
+        }
     }
 }
 
 impl Display for Types {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.to_xml_pretty(' ', 4)
-                .map_err(std::fmt::Error::custom)?
-        )
+        write!(f, "{}", self.render().map_err(|_| std::fmt::Error)?)
+    }
+}
+
+impl Eq for Types {}
+
+impl FromStr for Types {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (source, types) = Source::parse(s.parse()?)?;
+        Ok(Self::new(types, Some(source)))
+    }
+}
+
+impl PartialEq for Types {
+    fn eq(&self, other: &Self) -> bool {
+        self.types == other.types
     }
 }
 
 impl From<raw::Types> for Types {
     fn from(raw: raw::Types) -> Self {
-        Self {
-            types: raw.types.map_or(Vec::new(), |types| {
+        Self::new(
+            raw.types.map_or(Vec::new(), |types| {
                 types.into_iter().map(Type::from).collect()
             }),
-        }
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Source, Types};
+    use crate::merge::{Order, Strategy};
+    use crate::{CaseSensitivity, Type};
+
+    const BASE: &str = r#"<types>
+    <type name="Apple">
+        <nominal>20</nominal>
+        <lifetime>3600</lifetime>
+        <min>5</min>
+        <quantmax>-1</quantmax>
+        <flags count_in_cargo="0" count_in_hoarder="0" count_in_map="1" count_in_player="0" crafted="0" deloot="0"/>
+    </type>
+</types>
+"#;
+
+    fn partial(text: &str) -> Types {
+        let (source, types, _) = Source::recover(text.parse().unwrap()).unwrap();
+        Types::new(types, Some(source))
+    }
+
+    #[test]
+    fn overlays_the_present_fields_only() {
+        let base: Types = BASE.parse().unwrap();
+        let extension = partial(r#"<types><type name="Apple"><nominal>7</nominal></type></types>"#);
+        let merge = base.merge(&extension, Strategy::Overlay, Order::Preserve);
+        let apple = merge.types().get("Apple").unwrap();
+        assert_eq!(apple.get_nominal(), Some(7));
+        assert_eq!(apple.get_lifetime(), 3600);
+        assert_eq!(merge.overridden(), ["Apple"]);
+    }
+
+    #[test]
+    fn completes_new_partial_types_with_defaults() {
+        let base: Types = BASE.parse().unwrap();
+        let extension =
+            partial(r#"<types><type name="Banana"><nominal>7</nominal></type></types>"#);
+        let merge = base.merge(&extension, Strategy::Overlay, Order::Preserve);
+        assert_eq!(
+            merge.types().get("Banana"),
+            Some(&Type::builder("Banana").nominal(7).build().unwrap())
+        );
+    }
+
+    #[test]
+    fn compares_the_last_definition_by_name_according_to_the_case_sensitivity() {
+        let mut base: Types = BASE.parse().unwrap();
+        base.set_case_sensitivity(CaseSensitivity::Insensitive);
+        let other: Types = BASE
+            .replace(
+                "</types>",
+                &BASE
+                    .replace("Apple", "APPLE")
+                    .replace("<nominal>20</nominal>", "<nominal>1</nominal>")
+                    .replace("<types>\n", ""),
+            )
+            .parse()
+            .unwrap();
+        let diff = base.diff(&other);
+        assert!(diff.added().is_empty());
+        assert!(diff.removed().is_empty());
+        assert_eq!(diff.modified().len(), 1);
+    }
+
+    fn with_nominal(nominal: u32) -> Types {
+        BASE.replace(
+            "<nominal>20</nominal>",
+            &format!("<nominal>{nominal}</nominal>"),
+        )
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn merges_their_changes_into_fields_we_did_not_change() {
+        let base: Types = BASE.parse().unwrap();
+        let mut ours = base.clone();
+        ours.get_mut("Apple").unwrap().set_min(3);
+        let merge = ours.merge3(&base, &with_nominal(7));
+        assert!(merge.conflicts().is_empty());
+        let apple = merge.types().get("Apple").unwrap();
+        assert_eq!(apple.get_nominal(), Some(7));
+        assert_eq!(apple.get_min(), 3);
+    }
+
+    #[test]
+    fn keeps_our_value_of_conflicting_fields() {
+        let merge = with_nominal(8).merge3(&BASE.parse().unwrap(), &with_nominal(7));
+        assert_eq!(merge.conflicts().len(), 1);
+        assert_eq!(merge.types().get("Apple").unwrap().get_nominal(), Some(8));
+    }
+
+    #[test]
+    fn adds_their_new_types_once_with_their_last_definition() {
+        let base: Types = BASE.parse().unwrap();
+        let banana = |nominal: u32| {
+            BASE.replace("Apple", "Banana")
+                .replace(
+                    "<nominal>20</nominal>",
+                    &format!("<nominal>{nominal}</nominal>"),
+                )
+                .replace("<types>\n", "")
+        };
+        let theirs: Types = BASE
+            .replace(
+                "</types>",
+                &(banana(1).replace("</types>\n", "") + &banana(2)),
+            )
+            .parse()
+            .unwrap();
+        let merge = base.merge3(&base, &theirs);
+        assert_eq!(merge.types().types().count(), 2);
+        assert_eq!(merge.types().get("Banana").unwrap().get_nominal(), Some(2));
     }
 }
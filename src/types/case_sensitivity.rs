@@ -0,0 +1,21 @@
+use std::borrow::Cow;
+
+/// How type names are compared when looking types up by name
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CaseSensitivity {
+    /// Names must match exactly.
+    #[default]
+    Sensitive,
+    /// Names are compared ignoring ASCII case, like the game does.
+    Insensitive,
+}
+
+impl CaseSensitivity {
+    /// Return the key under which the given name is indexed.
+    pub(crate) fn key(self, name: &str) -> Cow<'_, str> {
+        match self {
+            Self::Sensitive => Cow::Borrowed(name),
+            Self::Insensitive => Cow::Owned(name.to_ascii_lowercase()),
+        }
+    }
+}
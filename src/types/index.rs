@@ -0,0 +1,117 @@
+use crate::types::CaseSensitivity;
+use crate::Type;
+use std::collections::HashMap;
+
+/// Positions of the types by their name
+///
+/// Keeps all positions of duplicate names in document order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Index {
+    positions: HashMap<String, Vec<usize>>,
+}
+
+impl Index {
+    pub fn new(types: &[Type], case: CaseSensitivity) -> Self {
+        let mut index = Self {
+            positions: HashMap::with_capacity(types.len()),
+        };
+
+        for (position, typ) in types.iter().enumerate() {
+            index.push(case.key(typ.get_name()).into_owned(), position);
+        }
+
+        index
+    }
+
+    /// Return the position of the first type with the given key.
+    pub fn first(&self, key: &str) -> Option<usize> {
+        self.all(key).first().copied()
+    }
+
+    /// Return the positions of all types with the given key in document order.
+    pub fn all(&self, key: &str) -> &[usize] {
+        self.positions.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Add a position under the given key.
+    pub fn push(&mut self, key: String, position: usize) {
+        let positions = self.positions.entry(key).or_default();
+        let index = positions.partition_point(|&other| other < position);
+        positions.insert(index, position);
+    }
+
+    /// Move a position from one key to another.
+    pub fn rename(&mut self, old: &str, new: String, position: usize) {
+        self.unlink(old, position);
+        self.push(new, position);
+    }
+
+    /// Remove the position of a type removed from the given key, shifting the following positions.
+    pub fn remove(&mut self, key: &str, position: usize) {
+        self.unlink(key, position);
+
+        for other in self.positions.values_mut().flatten() {
+            if *other > position {
+                *other -= 1;
+            }
+        }
+    }
+
+    /// Remove the positions flagged as removed, shifting the remaining positions accordingly.
+    pub fn retain(&mut self, removed: &[bool]) {
+        let mut shifted = Vec::with_capacity(removed.len());
+        let mut position = 0;
+
+        for &removed in removed {
+            shifted.push(position);
+            position += usize::from(!removed);
+        }
+
+        self.positions.retain(|_, positions| {
+            positions.retain(|&position| !removed.get(position).copied().unwrap_or(false));
+            positions
+                .iter_mut()
+                .for_each(|position| *position = shifted[*position]);
+            !positions.is_empty()
+        });
+    }
+
+    /// Move the positions of types renamed behind the index's back to their current keys.
+    pub fn refresh(&mut self, types: &[Type], case: CaseSensitivity) {
+        let renamed: Vec<(String, usize)> = types
+            .iter()
+            .enumerate()
+            .map(|(position, typ)| (case.key(typ.get_name()), position))
+            .filter(|(key, position)| self.all(key).binary_search(position).is_err())
+            .map(|(key, position)| (key.into_owned(), position))
+            .collect();
+
+        if renamed.is_empty() {
+            return;
+        }
+
+        let mut stale = vec![false; types.len()];
+        renamed
+            .iter()
+            .for_each(|&(_, position)| stale[position] = true);
+        self.positions.retain(|_, positions| {
+            positions.retain(|&position| !stale.get(position).copied().unwrap_or(false));
+            !positions.is_empty()
+        });
+
+        for (key, position) in renamed {
+            self.push(key, position);
+        }
+    }
+
+    /// Remove a position from the given key, dropping the key if it has no positions left.
+    fn unlink(&mut self, key: &str, position: usize) {
+        if let Some(positions) = self.positions.get_mut(key) {
+            positions.retain(|&other| other != position);
+
+            if positions.is_empty() {
+                self.positions.remove(key);
+            }
+        }
+    }
+}
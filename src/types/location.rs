@@ -0,0 +1,66 @@
+use crate::document::{Element, Span};
+
+/// Where a type and its fields are located in the document the types were read from
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location<'types> {
+    element: &'types Element,
+}
+
+impl<'types> Location<'types> {
+    pub(crate) const fn new(element: &'types Element) -> Self {
+        Self { element }
+    }
+
+    /// Return the span of the whole `<type>` element.
+    #[must_use]
+    pub const fn span(&self) -> Option<Span> {
+        self.element.span()
+    }
+
+    /// Return the span of the first element of the given field, e.g. `lifetime` or `usage`.
+    #[must_use]
+    pub fn field(&self, field: &str) -> Option<Span> {
+        self.fields(field).next()
+    }
+
+    /// Return the spans of all elements of the given field.
+    pub fn fields<'field>(&self, field: &'field str) -> impl Iterator<Item = Span> + 'field
+    where
+        'types: 'field,
+    {
+        self.element
+            .elements()
+            .filter(move |element| element.name() == field)
+            .filter_map(Element::span)
+    }
+
+    /// Return the span of the field element with the given name attribute, e.g. `usage` "Military".
+    ///
+    /// References to user-defined groups are matched by their `user` attribute.
+    #[must_use]
+    pub fn named(&self, field: &str, name: &str) -> Option<Span> {
+        self.element
+            .elements()
+            .filter(|element| element.name() == field)
+            .find(|element| {
+                element
+                    .attribute("name")
+                    .or_else(|| element.attribute("user"))
+                    .is_some_and(|attribute| attribute == name)
+            })
+            .and_then(Element::span)
+    }
+
+    /// Return the most specific span available for the given field and name.
+    ///
+    /// Falls back to the field's first element and then to the type itself.
+    #[must_use]
+    pub fn find(&self, field: Option<&str>, name: Option<&str>) -> Option<Span> {
+        field
+            .and_then(|field| {
+                name.and_then(|name| self.named(field, name))
+                    .or_else(|| self.field(field))
+            })
+            .or_else(|| self.span())
+    }
+}
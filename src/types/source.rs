@@ -0,0 +1,294 @@
+use crate::document::{arrange, assign, Document, Element};
+use crate::recovery::Recovery;
+use crate::types::index::Index;
+use crate::types::CaseSensitivity;
+use crate::Type;
+use std::sync::OnceLock;
+
+const DEFAULT_INDENT: &str = "\n    ";
+
+/// The document a `Types` collection was read from
+///
+/// Keeps the original types alongside the document, so that changes can be
+/// written back onto the respective `<type>` elements only.
+/// The `<type>` elements are indexed by name to locate types without scanning the document.
+#[derive(Clone, Debug)]
+pub struct Source {
+    document: Document,
+    types: Vec<Type>,
+    fields: Vec<Vec<String>>,
+    nodes: Vec<usize>,
+    sensitive: OnceLock<Index>,
+    insensitive: OnceLock<Index>,
+}
+
+impl Source {
+    /// Parse the types from the given document strictly.
+    pub fn parse(document: Document) -> Result<(Self, Vec<Type>), anyhow::Error> {
+        let types = type_elements(&document)?
+            .map(|element| Type::from_element(element).map_err(|error| located(element, &error)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let fields = type_elements(&document)?.map(fields).collect();
+        Ok((Self::new(document, types.clone(), fields), types))
+    }
+
+    /// Parse the types from the given document gracefully.
+    ///
+    /// Returns the values that could not be read as is alongside the types.
+    pub fn recover(
+        mut document: Document,
+    ) -> Result<(Self, Vec<Type>, Vec<Recovery>), anyhow::Error> {
+        let mut types = Vec::new();
+        let mut present = Vec::new();
+        let mut recoveries = Vec::new();
+        let root = document
+            .root_mut()
+            .filter(|root| root.name() == "types")
+            .ok_or_else(|| anyhow::Error::msg("missing root element <types>"))?;
+        let child_indent = format!("{DEFAULT_INDENT}    ");
+
+        for element in root
+            .elements_mut()
+            .filter(|element| element.name() == "type")
+        {
+            let (typ, mut type_recoveries) =
+                Type::recover_element(element).map_err(|error| located(element, &error))?;
+            present.push(fields(element));
+            let fields: Vec<&str> = type_recoveries
+                .iter()
+                .filter_map(Recovery::field)
+                .filter_map(|field| field.split('.').next())
+                .collect();
+
+            if !fields.is_empty() {
+                typ.rewrite_fields(element, &fields, &child_indent)?;
+            }
+
+            types.push(typ);
+            recoveries.append(&mut type_recoveries);
+        }
+
+        Ok((
+            Self::new(document, types.clone(), present),
+            types,
+            recoveries,
+        ))
+    }
+
+    /// Return the fields present in each `<type>` element as it was read, in document order.
+    ///
+    /// Flags are listed per attribute, e.g. `flags.count_in_map`.
+    pub fn fields(&self) -> &[Vec<String>] {
+        &self.fields
+    }
+
+    /// Return whether the given types are still the ones read from the document.
+    pub fn is_unchanged(&self, types: &[Type]) -> bool {
+        self.types == types
+    }
+
+    /// Return the `<type>` element at the given position in document order.
+    pub fn element(&self, position: usize) -> Option<&Element> {
+        self.document
+            .root()?
+            .children()
+            .get(*self.nodes.get(position)?)?
+            .as_element()
+    }
+
+    /// Return the positions of all `<type>` elements with the given name in document order.
+    pub fn positions(&self, name: &str, case: CaseSensitivity) -> &[usize] {
+        let index = match case {
+            CaseSensitivity::Sensitive => &self.sensitive,
+            CaseSensitivity::Insensitive => &self.insensitive,
+        };
+        index
+            .get_or_init(|| Index::new(&self.types, case))
+            .all(&case.key(name))
+    }
+
+    /// Return the position of the `<type>` element the type at the given position was read from.
+    ///
+    /// This is the element at the same position if it has the same name,
+    /// and the first element with the type's name otherwise.
+    pub fn origin(&self, position: usize, name: &str, case: CaseSensitivity) -> Option<usize> {
+        self.types
+            .get(position)
+            .filter(|typ| case.key(typ.get_name()) == case.key(name))
+            .map(|_| position)
+            .or_else(|| self.positions(name, case).first().copied())
+    }
+
+    /// Render the given types onto the original document in the given order.
+    ///
+    /// Types are matched to their original elements by name, or to those of other names
+    /// if they were evidently renamed, see [`assign`]. Removed types take their preceding
+    /// whitespace with them, while comments and unknown elements before them are kept.
+    pub fn render(&self, types: &[Type]) -> Result<Document, anyhow::Error> {
+        let mut document = self.document.clone();
+        let child_indent = self
+            .element(0)
+            .and_then(Element::child_indent)
+            .map(ToString::to_string);
+        let root = document
+            .root_mut()
+            .ok_or_else(|| anyhow::Error::msg("missing root element"))?;
+        let assignments = assign(&self.types, types, ToString::to_string);
+        arrange(root, "type", &assignments, |index, original, indent| {
+            let typ = &types[index];
+            let child_indent = child_indent
+                .clone()
+                .unwrap_or_else(|| format!("{indent}    "));
+
+            match original {
+                Some((original, mut element)) => {
+                    typ.update_element(&self.types[original], &mut element, indent, &child_indent)?;
+                    Ok(element)
+                }
+                None => typ.to_element(indent, &child_indent),
+            }
+        })?;
+        Ok(document)
+    }
+
+    fn new(document: Document, types: Vec<Type>, fields: Vec<Vec<String>>) -> Self {
+        let nodes = document
+            .root()
+            .map(|root| {
+                root.children()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, node)| node.is_element_named("type"))
+                    .map(|(index, _)| index)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            document,
+            types,
+            fields,
+            nodes,
+            sensitive: OnceLock::new(),
+            insensitive: OnceLock::new(),
+        }
+    }
+}
+
+/// Return the `<type>` elements of the document's `<types>` root.
+fn type_elements(document: &Document) -> Result<impl Iterator<Item = &Element>, anyhow::Error> {
+    Ok(document
+        .root()
+        .filter(|root| root.name() == "types")
+        .ok_or_else(|| anyhow::Error::msg("missing root element <types>"))?
+        .elements()
+        .filter(|element| element.name() == "type"))
+}
+
+fn located(element: &Element, error: &anyhow::Error) -> anyhow::Error {
+    let name = element.attribute("name").unwrap_or_default();
+    anyhow::Error::msg(element.position().map_or_else(
+        || format!("type {name}: {error}"),
+        |position| format!("type {name} at {position}: {error}"),
+    ))
+}
+
+fn fields(element: &Element) -> Vec<String> {
+    let mut fields = Vec::new();
+
+    for child in element.elements() {
+        if child.name() == "flags" {
+            fields.extend(
+                child
+                    .attribute_names()
+                    .map(|attribute| format!("flags.{attribute}")),
+            );
+        }
+
+        fields.push(child.name().to_string());
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+    use crate::Type;
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<types>
+    <type name="Apple">
+        <nominal>20</nominal>
+        <lifetime>3600</lifetime>
+        <min>5</min>
+        <quantmax>-1</quantmax>
+        <flags count_in_cargo="0" count_in_hoarder="0" count_in_map="1" count_in_player="0" crafted="0" deloot="0"/>
+    </type>
+    <!-- banana mod -->
+    <type name="Banana">
+        <nominal>10</nominal>
+        <lifetime>3600</lifetime>
+        <min>5</min>
+        <quantmax>-1</quantmax>
+        <flags count_in_cargo="0" count_in_hoarder="0" count_in_map="1" count_in_player="0" crafted="0" deloot="0"/>
+        <bananamod x="1"/>
+    </type>
+</types>
+"#;
+
+    fn parse() -> (Source, Vec<Type>) {
+        Source::parse(DOCUMENT.parse().unwrap()).unwrap()
+    }
+
+    fn render(source: &Source, types: &[Type]) -> String {
+        source.render(types).unwrap().to_string()
+    }
+
+    #[test]
+    fn writes_unchanged_types_as_read() {
+        let (source, types) = parse();
+        assert_eq!(render(&source, &types), DOCUMENT);
+    }
+
+    #[test]
+    fn updates_changed_fields_only() {
+        let (source, mut types) = parse();
+        types[1].set_nominal(Some(3));
+        assert_eq!(
+            render(&source, &types),
+            DOCUMENT.replace("<nominal>10</nominal>", "<nominal>3</nominal>")
+        );
+    }
+
+    #[test]
+    fn keeps_the_element_of_renamed_types() {
+        let (source, mut types) = parse();
+        types[1].set_name("Kiwi");
+        assert_eq!(
+            render(&source, &types),
+            DOCUMENT.replace("name=\"Banana\"", "name=\"Kiwi\"")
+        );
+    }
+
+    #[test]
+    fn writes_added_types_into_new_elements() {
+        let (source, mut types) = parse();
+        let mut cherry = types.remove(1);
+        cherry.set_name("Cherry");
+        cherry.set_nominal(Some(7));
+        types.push(cherry);
+        let rendered = render(&source, &types);
+        assert!(rendered.contains("<type name=\"Cherry\">\n        <nominal>7</nominal>"));
+        assert!(!rendered.contains("bananamod"));
+        assert!(!rendered.contains("Banana"));
+    }
+
+    #[test]
+    fn keeps_the_given_order() {
+        let (source, mut types) = parse();
+        types.reverse();
+        let rendered = render(&source, &types);
+        assert!(rendered.find("<!-- banana mod -->").unwrap() < rendered.find("Banana").unwrap());
+        assert!(rendered.find("Banana").unwrap() < rendered.find("Apple").unwrap());
+    }
+}
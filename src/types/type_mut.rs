@@ -0,0 +1,60 @@
+use crate::types::index::Index;
+use crate::types::CaseSensitivity;
+use crate::Type;
+use std::ops::{Deref, DerefMut};
+use std::sync::OnceLock;
+
+/// A mutable reference to a type within `Types`
+///
+/// Keeps the name index up to date if the type is renamed.
+#[derive(Debug)]
+pub struct TypeMut<'types> {
+    typ: &'types mut Type,
+    position: usize,
+    key: String,
+    index: &'types mut OnceLock<Index>,
+    case: CaseSensitivity,
+}
+
+impl<'types> TypeMut<'types> {
+    pub(crate) fn new(
+        typ: &'types mut Type,
+        position: usize,
+        index: &'types mut OnceLock<Index>,
+        case: CaseSensitivity,
+    ) -> Self {
+        Self {
+            key: case.key(typ.get_name()).into_owned(),
+            typ,
+            position,
+            index,
+            case,
+        }
+    }
+}
+
+impl Deref for TypeMut<'_> {
+    type Target = Type;
+
+    fn deref(&self) -> &Self::Target {
+        self.typ
+    }
+}
+
+impl DerefMut for TypeMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.typ
+    }
+}
+
+impl Drop for TypeMut<'_> {
+    fn drop(&mut self) {
+        let key = self.case.key(self.typ.get_name());
+
+        if key != self.key {
+            if let Some(index) = self.index.get_mut() {
+                index.rename(&self.key, key.into_owned(), self.position);
+            }
+        }
+    }
+}
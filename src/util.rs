@@ -29,5 +29,48 @@ where
 pub fn parse_bool_or_false(string: &str) -> bool {
     string
         .parse::<bool>()
-        .unwrap_or_else(|_| string.parse::<u8>().map_or(false, |int| int != 0))
+        .unwrap_or_else(|_| string.parse::<u8>().is_ok_and(|int| int != 0))
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub fn as_empty_element<S>(_: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_unit()
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub fn as_decimal<S>(value: &f32, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&fmt_decimal(*value))
+}
+
+#[allow(clippy::ref_option)]
+pub fn as_optional_decimal<S>(value: &Option<f32>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_str(&fmt_decimal(*value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Format the number with at least two decimal places, as the game's files do.
+pub fn fmt_decimal(value: f32) -> String {
+    let string = value.to_string();
+
+    match string.split_once('.') {
+        None => format!("{string}.00"),
+        Some((_, decimals)) if decimals.len() < 2 => format!("{string}0"),
+        Some(_) => string,
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub const fn is_false(value: &bool) -> bool {
+    !*value
 }
@@ -0,0 +1,115 @@
+//! Semantic validation of types.
+
+mod diagnostic;
+mod rule;
+mod severity;
+mod subject;
+
+use crate::{Type, Types};
+
+pub use diagnostic::Diagnostic;
+pub use rule::Rule;
+pub use severity::Severity;
+pub use subject::Subject;
+
+/// Maximum amount of usages the game takes into account per type.
+pub const MAX_USAGES: usize = 4;
+
+/// Maximum nominal and min the game can hold, as it stores them as signed 32 bit integers.
+#[allow(clippy::cast_sign_loss)]
+pub const MAX_AMOUNT: u32 = i32::MAX as u32;
+
+/// Validate all types of the given collection.
+///
+/// The diagnostics point to the offending fields if the types were read from a file.
+#[must_use]
+pub fn validate(types: &Types) -> Vec<Diagnostic> {
+    types
+        .types()
+        .enumerate()
+        .flat_map(|(position, typ)| {
+            validate_type(typ)
+                .into_iter()
+                .map(move |diagnostic| diagnostic.locate(types, position))
+        })
+        .collect()
+}
+
+/// Validate a single type.
+#[must_use]
+pub fn validate_type(typ: &Type) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let nominal = typ.get_nominal().unwrap_or(0);
+
+    if nominal > MAX_AMOUNT {
+        diagnostics.push(Diagnostic::new(
+            Rule::NominalOutOfRange,
+            typ.get_name(),
+            format!("nominal ({nominal}) exceeds the maximum of {MAX_AMOUNT}"),
+        ));
+    }
+
+    if typ.get_min() > MAX_AMOUNT {
+        diagnostics.push(Diagnostic::new(
+            Rule::MinOutOfRange,
+            typ.get_name(),
+            format!(
+                "min ({}) exceeds the maximum of {MAX_AMOUNT}",
+                typ.get_min()
+            ),
+        ));
+    }
+
+    if nominal == 0 && typ.get_min() > 0 {
+        diagnostics.push(Diagnostic::new(
+            Rule::MinWithoutNominal,
+            typ.get_name(),
+            format!("min is {} but nominal is zero or unset", typ.get_min()),
+        ));
+    } else if typ.get_min() > nominal {
+        diagnostics.push(Diagnostic::new(
+            Rule::MinExceedsNominal,
+            typ.get_name(),
+            format!("min ({}) exceeds nominal ({nominal})", typ.get_min()),
+        ));
+    }
+
+    if let Some(quantmin) = typ.get_quantmin() {
+        if quantmin != -1 && typ.get_quantmax() == -1 {
+            diagnostics.push(Diagnostic::new(
+                Rule::QuantminWithoutQuantmax,
+                typ.get_name(),
+                format!("quantmin is {quantmin} but quantmax is unset (-1)"),
+            ));
+        } else if quantmin > typ.get_quantmax() {
+            diagnostics.push(Diagnostic::new(
+                Rule::QuantminExceedsQuantmax,
+                typ.get_name(),
+                format!(
+                    "quantmin ({quantmin}) exceeds quantmax ({})",
+                    typ.get_quantmax()
+                ),
+            ));
+        }
+    }
+
+    if let Some(usages) = typ.get_usages() {
+        if usages.len() > MAX_USAGES {
+            diagnostics.push(Diagnostic::new(
+                Rule::TooManyUsages,
+                typ.get_name(),
+                format!("{} usages, only up to {MAX_USAGES} are used", usages.len()),
+            ));
+        }
+    }
+
+    if typ.get_lifetime() == 0 {
+        diagnostics.push(Diagnostic::new(
+            Rule::ZeroLifetime,
+            typ.get_name(),
+            "lifetime is zero".to_string(),
+        ));
+    }
+
+    diagnostics
+}
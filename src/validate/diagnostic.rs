@@ -0,0 +1,138 @@
+use crate::document::Position;
+use crate::validate::{Rule, Severity, Subject};
+use crate::Types;
+use std::fmt::{Display, Formatter};
+
+/// A rule violation found in a type, a user-defined group or a registered file
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    rule: Rule,
+    severity: Severity,
+    subject: Subject,
+    message: String,
+    suggestion: Option<String>,
+    item: Option<String>,
+    position: Option<Position>,
+}
+
+impl Diagnostic {
+    /// Create a diagnostic for the type with the given name.
+    #[must_use]
+    pub fn new(rule: Rule, type_name: &str, message: String) -> Self {
+        Self::concerning(rule, Subject::Type(type_name.to_string()), message)
+    }
+
+    /// Create a diagnostic for the given subject.
+    #[must_use]
+    pub const fn concerning(rule: Rule, subject: Subject, message: String) -> Self {
+        Self {
+            rule,
+            severity: rule.severity(),
+            subject,
+            message,
+            suggestion: None,
+            item: None,
+            position: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_suggestion(mut self, suggestion: &str) -> Self {
+        self.suggestion = Some(suggestion.to_string());
+        self
+    }
+
+    /// Set the name of the category, tag, usage or value the diagnostic refers to.
+    #[must_use]
+    pub fn with_item(mut self, item: &str) -> Self {
+        self.item = Some(item.to_string());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_position(mut self, position: Option<Position>) -> Self {
+        self.position = position;
+        self
+    }
+
+    #[must_use]
+    pub const fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    #[must_use]
+    pub const fn subject(&self) -> &Subject {
+        &self.subject
+    }
+
+    /// Return the name of the type the diagnostic refers to, if it refers to a type.
+    #[must_use]
+    pub fn type_name(&self) -> Option<&str> {
+        match self.subject {
+            Subject::Type(ref name) => Some(name.as_str()),
+            Subject::UserGroup(_) | Subject::File(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&str> {
+        self.suggestion.as_deref()
+    }
+
+    #[must_use]
+    pub fn item(&self) -> Option<&str> {
+        self.item.as_deref()
+    }
+
+    /// Return the position of the offending field in the types.xml, if known.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Locate the diagnostic's field in the type at the given position of the document the types were read from.
+    ///
+    /// Diagnostics of event and file rules are left as they are, since they do not refer to a type.
+    pub(crate) fn locate(self, types: &Types, position: usize) -> Self {
+        if !self.rule.is_type_rule() {
+            return self;
+        }
+
+        let position = types.locate_at(position).and_then(|location| {
+            location
+                .find(Some(self.rule.field()), self.item.as_deref())
+                .map(|span| span.start())
+        });
+        self.with_position(position)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(position) = self.position {
+            write!(f, "{position}: ")?;
+        }
+
+        write!(
+            f,
+            "{}[{}] {}: {}",
+            self.severity, self.rule, self.subject, self.message
+        )?;
+
+        if let Some(ref suggestion) = self.suggestion {
+            write!(f, ", did you mean \"{suggestion}\"?")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::validate::Severity;
+use std::fmt::{Display, Formatter};
+
+/// A semantic rule a type, event or registered file must obey
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    EventWithoutSpawns,
+    MinExceedsNominal,
+    MinOutOfRange,
+    MinWithoutNominal,
+    MissingFile,
+    NominalOutOfRange,
+    QuantminExceedsQuantmax,
+    QuantminWithoutQuantmax,
+    SpawnsWithoutEvent,
+    TooManyUsages,
+    TypeOverridden,
+    UnknownCategory,
+    UnknownTag,
+    UnknownUsage,
+    UnknownValue,
+    ZeroLifetime,
+}
+
+impl Rule {
+    /// Return the rule's stable identifier.
+    #[must_use]
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Self::EventWithoutSpawns => "event-without-spawns",
+            Self::MinExceedsNominal => "min-exceeds-nominal",
+            Self::MinOutOfRange => "min-out-of-range",
+            Self::MinWithoutNominal => "min-without-nominal",
+            Self::MissingFile => "missing-file",
+            Self::NominalOutOfRange => "nominal-out-of-range",
+            Self::QuantminExceedsQuantmax => "quantmin-exceeds-quantmax",
+            Self::QuantminWithoutQuantmax => "quantmin-without-quantmax",
+            Self::SpawnsWithoutEvent => "spawns-without-event",
+            Self::TooManyUsages => "too-many-usages",
+            Self::TypeOverridden => "type-overridden",
+            Self::UnknownCategory => "unknown-category",
+            Self::UnknownTag => "unknown-tag",
+            Self::UnknownUsage => "unknown-usage",
+            Self::UnknownValue => "unknown-value",
+            Self::ZeroLifetime => "zero-lifetime",
+        }
+    }
+
+    /// Return whether this rule applies to types, as opposed to events or files.
+    #[must_use]
+    pub const fn is_type_rule(&self) -> bool {
+        !matches!(
+            self,
+            Self::EventWithoutSpawns | Self::MissingFile | Self::SpawnsWithoutEvent
+        )
+    }
+
+    /// Return the field of a type or event this rule is concerned with.
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::EventWithoutSpawns | Self::SpawnsWithoutEvent => "position",
+            Self::MinExceedsNominal | Self::MinOutOfRange | Self::MinWithoutNominal => "min",
+            Self::MissingFile => "file",
+            Self::NominalOutOfRange => "nominal",
+            Self::QuantminExceedsQuantmax | Self::QuantminWithoutQuantmax => "quantmin",
+            Self::TooManyUsages | Self::UnknownUsage => "usage",
+            Self::TypeOverridden => "name",
+            Self::UnknownCategory => "category",
+            Self::UnknownTag => "tag",
+            Self::UnknownValue => "value",
+            Self::ZeroLifetime => "lifetime",
+        }
+    }
+
+    /// Return the severity of violating this rule.
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Self::EventWithoutSpawns
+            | Self::MinExceedsNominal
+            | Self::MinOutOfRange
+            | Self::MissingFile
+            | Self::NominalOutOfRange
+            | Self::QuantminExceedsQuantmax
+            | Self::QuantminWithoutQuantmax
+            | Self::UnknownCategory
+            | Self::UnknownTag
+            | Self::UnknownUsage
+            | Self::UnknownValue
+            | Self::ZeroLifetime => Severity::Error,
+            Self::MinWithoutNominal
+            | Self::SpawnsWithoutEvent
+            | Self::TooManyUsages
+            | Self::TypeOverridden => Severity::Warning,
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt::{Display, Formatter};
+
+/// What a diagnostic refers to
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Subject {
+    /// A type, by name
+    Type(String),
+    /// A user-defined group of usages or values, by name
+    UserGroup(String),
+    /// A file registered in `cfgeconomycore.xml`, by its registration
+    File(String),
+}
+
+impl Subject {
+    /// Return the name of the type, group or file.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Type(name) | Self::UserGroup(name) | Self::File(name) => name.as_str(),
+        }
+    }
+}
+
+impl Display for Subject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Type(name) | Self::File(name) => write!(f, "{name}"),
+            Self::UserGroup(name) => write!(f, "user group {name}"),
+        }
+    }
+}